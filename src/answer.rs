@@ -0,0 +1,224 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged, from = "Raw")]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Art(Vec<String>),
+}
+
+// answers are read like `Answer::from`, text that reads as a number is an integer
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Raw {
+    Integer(i64),
+    Text(String),
+    Art(Vec<String>),
+}
+
+impl From<Raw> for Answer {
+    fn from(raw: Raw) -> Self {
+        match raw {
+            Raw::Integer(value) => Self::Integer(value),
+            Raw::Text(value) => Self::from(value),
+            Raw::Art(lines) => Self::Art(lines),
+        }
+    }
+}
+
+// the letters drawn by the puzzles, 6 rows high and separated by a blank column
+const FONT: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+impl Answer {
+    pub fn art(art: &str) -> Self {
+        Self::Art(
+            art.lines()
+                .map(|line| line.trim_end().to_string())
+                .collect(),
+        )
+    }
+
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Integer(value) => expected.trim().parse::<i64>() == Ok(*value),
+            Self::Text(value) => expected.trim() == value.trim(),
+            Self::Art(lines) => expected
+                .trim_matches('\n')
+                .lines()
+                .map(str::trim_end)
+                .eq(lines.iter().map(String::as_str)),
+        }
+    }
+
+    pub fn suspicious(&self) -> Option<&'static str> {
+        match self {
+            Self::Integer(value) if *value < 0 => Some("answer is negative"),
+            Self::Integer(0) => Some("answer is zero"),
+            Self::Text(value) if value.trim().is_empty() => Some("answer is empty"),
+            _ => None,
+        }
+    }
+
+    // what gets sent to the site, ascii art is read into its letters first
+    pub fn submission(&self) -> Result<Self, String> {
+        match self {
+            Self::Art(lines) => Answer::letters(lines)
+                .map(Self::Text)
+                .ok_or_else(|| "answer is ascii art that could not be read".to_string()),
+            answer => Ok(answer.clone()),
+        }
+    }
+
+    fn letters(lines: &[String]) -> Option<String> {
+        let lit = |c: char| c != '.' && !c.is_whitespace();
+
+        let rows = lines
+            .iter()
+            .map(|line| line.chars().map(lit).collect::<Vec<_>>())
+            .skip_while(|row| !row.contains(&true))
+            .collect::<Vec<_>>();
+
+        let rows = match rows.iter().rposition(|row| row.contains(&true)) {
+            Some(5) => &rows[..6],
+            _ => return None,
+        };
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let blank = |x: usize| rows.iter().all(|row| !row.get(x).copied().unwrap_or(false));
+
+        let mut letters = String::new();
+        let mut x = 0;
+
+        while x < width {
+            if blank(x) {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+
+            while x < width && !blank(x) {
+                x += 1;
+            }
+
+            let glyph = rows
+                .iter()
+                .map(|row| {
+                    (start..x)
+                        .map(|x| match row.get(x).copied().unwrap_or(false) {
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+
+            let (letter, _) = FONT.iter().find(|(_, font)| glyph.iter().eq(font.iter()))?;
+
+            letters.push(*letter);
+        }
+
+        Some(letters).filter(|letters| !letters.is_empty())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => f.pad(&value.to_string()),
+            Self::Text(value) => f.pad(value),
+            Self::Art(lines) => f.pad(&format!("\n{}", lines.join("\n"))),
+        }
+    }
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Self::Integer(value),
+                        Err(_) => Self::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// a number given as text is the same answer as the number, i.e "1234" and 1234
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        match value.parse::<i64>() {
+            Ok(number) if number.to_string() == value => Self::Integer(number),
+            _ => Self::Text(value),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ART: &str = "
+#..#.###.
+#..#..#..
+####..#..
+#..#..#..
+#..#..#..
+#..#.###.";
+
+    #[test]
+    fn text_reading_as_a_number_is_an_integer() {
+        let answer: Answer = serde_json::from_str("\"1234\"").expect("failed to parse answer");
+
+        assert_eq!(answer, Answer::Integer(1234));
+        assert_eq!(Answer::from("0012"), Answer::Text("0012".to_string()));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+    }
+
+    #[test]
+    fn art_is_submitted_as_letters() {
+        assert_eq!(
+            Answer::art(ART).submission(),
+            Ok(Answer::Text("HI".to_string()))
+        );
+        assert_eq!(
+            Answer::art(&ART.replace('.', " ")).submission(),
+            Ok(Answer::Text("HI".to_string()))
+        );
+        assert!(Answer::art("##\n##").submission().is_err());
+        assert_eq!(Answer::Integer(7).submission(), Ok(Answer::Integer(7)));
+    }
+}
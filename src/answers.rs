@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_client::SubmissionOutcome;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::client::{Hint, Submission};
+use crate::template::Puzzle;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    pub parts: BTreeMap<String, Record>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(default)]
+    pub accepted: Option<Answer>,
+    #[serde(default)]
+    pub guesses: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_until: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: Answer,
+    pub status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    Correct,
+    Incorrect,
+    Wait,
+    WrongLevel,
+}
+
+impl From<&SubmissionOutcome> for Status {
+    fn from(outcome: &SubmissionOutcome) -> Self {
+        match outcome {
+            SubmissionOutcome::Correct => Self::Correct,
+            SubmissionOutcome::Incorrect => Self::Incorrect,
+            SubmissionOutcome::Wait => Self::Wait,
+            SubmissionOutcome::WrongLevel => Self::WrongLevel,
+        }
+    }
+}
+
+impl Record {
+    pub fn cooldown(&self) -> Option<Duration> {
+        let until = self.cooldown_until?;
+        let now = now();
+
+        match until > now {
+            true => Some(Duration::from_secs(until - now)),
+            false => None,
+        }
+    }
+
+    pub fn check(&self, answer: &Answer) -> Result<(), String> {
+        if let Some(accepted) = &self.accepted {
+            return match accepted == answer {
+                true => Err("answer was already accepted".to_string()),
+                false => Err(format!("puzzle was already solved with {}", accepted)),
+            };
+        }
+
+        if self
+            .guesses
+            .iter()
+            .any(|guess| guess.status == Status::Incorrect && &guess.answer == answer)
+        {
+            return Err("answer was already rejected".to_string());
+        }
+
+        let value = match answer {
+            Answer::Integer(value) => *value,
+            _ => return Ok(()),
+        };
+
+        let bound = |hint: Hint| {
+            self.guesses
+                .iter()
+                .filter(move |guess| guess.hint == Some(hint))
+                .filter_map(|guess| match guess.answer {
+                    Answer::Integer(value) => Some(value),
+                    _ => None,
+                })
+        };
+
+        if let Some(low) = bound(Hint::TooLow).max() {
+            if value <= low {
+                return Err(format!("answer is too low, must be above {}", low));
+            }
+        }
+
+        if let Some(high) = bound(Hint::TooHigh).min() {
+            if value >= high {
+                return Err(format!("answer is too high, must be below {}", high));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Answers {
+    pub fn load(puzzle: &Puzzle) -> Result<Self, String> {
+        match std::fs::read_to_string(&puzzle.answers_path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|err| format!("unable to parse {}: {}", puzzle.answers_path, err)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, puzzle: &Puzzle) -> Result<(), String> {
+        if let Some(dir) = std::path::Path::new(&puzzle.answers_path).parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("unable to create {}: {}", dir.display(), err))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| format!("unable to serialize answers: {}", err))?;
+
+        std::fs::write(&puzzle.answers_path, content)
+            .map_err(|err| format!("unable to write {}: {}", puzzle.answers_path, err))
+    }
+
+    pub fn record(&self, puzzle: &Puzzle) -> Option<&Record> {
+        self.parts.get(&puzzle.part)
+    }
+
+    pub fn accepted(&self, puzzle: &Puzzle) -> Option<&Answer> {
+        self.record(puzzle)?.accepted.as_ref()
+    }
+
+    pub fn check(&self, puzzle: &Puzzle, answer: &Answer) -> Result<(), String> {
+        match self.record(puzzle) {
+            Some(record) => record.check(answer),
+            None => Ok(()),
+        }
+    }
+
+    pub fn cooldown(&self, puzzle: &Puzzle) -> Option<Duration> {
+        self.record(puzzle)?.cooldown()
+    }
+
+    pub fn submitted(&mut self, puzzle: &Puzzle, answer: &Answer, submission: &Submission) {
+        let record = self.parts.entry(puzzle.part.clone()).or_default();
+        let status = Status::from(&submission.outcome);
+
+        if status == Status::Correct {
+            record.accepted = Some(answer.clone());
+        }
+
+        record.cooldown_until = submission.wait.map(|wait| now() + wait.as_secs());
+
+        record.guesses.push(Guess {
+            answer: answer.clone(),
+            status,
+            hint: submission.hint,
+        });
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the unix epoch")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(answer: i64, hint: Option<Hint>) -> Guess {
+        Guess {
+            answer: Answer::Integer(answer),
+            status: Status::Incorrect,
+            hint,
+        }
+    }
+
+    #[test]
+    fn hints_bound_the_next_answers() {
+        let record = Record {
+            guesses: vec![
+                guess(100, Some(Hint::TooLow)),
+                guess(120, Some(Hint::TooLow)),
+                guess(300, Some(Hint::TooHigh)),
+                guess(200, Some(Hint::TooHigh)),
+            ],
+            ..Record::default()
+        };
+
+        assert_eq!(
+            record.check(&Answer::Integer(120)),
+            Err("answer was already rejected".to_string())
+        );
+        assert_eq!(
+            record.check(&Answer::Integer(110)),
+            Err("answer is too low, must be above 120".to_string())
+        );
+        assert_eq!(
+            record.check(&Answer::Integer(250)),
+            Err("answer is too high, must be below 200".to_string())
+        );
+        assert_eq!(record.check(&Answer::Integer(150)), Ok(()));
+        assert_eq!(record.check(&Answer::from("abc")), Ok(()));
+    }
+
+    #[test]
+    fn unreadable_answers_are_reported() {
+        let dir = tempfile::tempdir().expect("failed to create dir");
+        let mut puzzle = Puzzle::parse("2015_01_01").expect("failed to parse puzzle");
+
+        puzzle.answers_path = dir.path().join("2015_01.json").display().to_string();
+
+        assert!(Answers::load(&puzzle).is_ok_and(|answers| answers.parts.is_empty()));
+
+        std::fs::write(&puzzle.answers_path, "{ \"parts\": ").expect("failed to write answers");
+
+        assert!(Answers::load(&puzzle).is_err_and(|err| err.starts_with("unable to parse")));
+    }
+
+    #[test]
+    fn text_answers_match_numbers() {
+        let record: Record = serde_json::from_str(
+            r#"{ "accepted": null, "guesses": [{ "answer": "1234", "status": "incorrect" }] }"#,
+        )
+        .expect("failed to parse record");
+
+        assert_eq!(
+            record.check(&Answer::Integer(1234)),
+            Err("answer was already rejected".to_string())
+        );
+
+        let record: Record =
+            serde_json::from_str(r#"{ "accepted": "42" }"#).expect("failed to parse record");
+
+        assert_eq!(
+            record.check(&Answer::Integer(42)),
+            Err("answer was already accepted".to_string())
+        );
+    }
+}
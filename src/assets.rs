@@ -1,33 +1,241 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::config;
 use crate::template::Puzzle;
 
-const PUZZLE_BIN_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/assets/bin.txt"));
+macro_rules! builtin {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/assets/", $name))),)*]
+    };
+}
 
-const PUZZLE_SOLUTION_TEMPLATE: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/src/assets/solution.txt"
-));
+// builtin templates, a variant lives in its own directory and falls back to the default ones
+const BUILTIN: &[(&str, &str)] = builtin![
+    "bin.txt",
+    "day.txt",
+    "example.txt",
+    "header.txt",
+    "mod.txt",
+    "solution.txt",
+    "grid/day.txt",
+    "grid/solution.txt",
+    "lines/day.txt",
+    "lines/parse.txt",
+    "lines/solution.txt",
+];
 
-const PUZZLE_MOD_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/assets/mod.txt"));
+// what a template knows about the puzzle it scaffolds
+#[derive(Debug, Clone, Serialize)]
+pub struct Context {
+    pub id: String,
+    pub day_id: String,
+    pub module: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: Option<String>,
+    pub input: Option<Hints>,
+}
 
-const PUZZLE_EXAMPLE_TEMPLATE: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/src/assets/example.txt"
-));
+// the shape of the puzzle input, for templates to pick a fitting parser
+#[derive(Debug, Clone, Serialize)]
+pub struct Hints {
+    pub kind: Kind,
+    pub lines: usize,
+    pub width: usize,
+}
 
-pub fn bin_template(puzzle: &Puzzle) -> String {
-    PUZZLE_BIN_TEMPLATE.replace("%puzzle%", &puzzle.dir_name)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Text,
+    Numbers,
+    Grid,
+    Lines,
 }
 
-pub fn solution_template(puzzle: &Puzzle) -> String {
-    PUZZLE_SOLUTION_TEMPLATE.replace("%puzzle%", &puzzle.id)
+impl Context {
+    pub fn new(puzzle: &Puzzle, description: &str, input: Option<&str>) -> Self {
+        Self {
+            id: puzzle.id.clone(),
+            day_id: format!("{}_{}", puzzle.year, puzzle.day),
+            module: puzzle.module.clone(),
+            year: puzzle.year.parse().unwrap_or_default(),
+            day: puzzle.day.parse().unwrap_or_default(),
+            part: puzzle.part.parse().unwrap_or_default(),
+            title: title(description),
+            input: input.map(Hints::new),
+        }
+    }
 }
 
-pub fn mod_template(puzzle: &Puzzle) -> String {
-    PUZZLE_MOD_TEMPLATE.replace("%puzzle%", &puzzle.id)
+impl Hints {
+    pub fn new(input: &str) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        let numbers = lines
+            .iter()
+            .all(|line| line.split_whitespace().all(|n| n.parse::<i64>().is_ok()));
+
+        // a grid is drawn with symbols and digits or a few letters, word lists are not grids
+        let mut chars = lines
+            .iter()
+            .flat_map(|line| line.chars())
+            .collect::<Vec<_>>();
+
+        chars.sort_unstable();
+        chars.dedup();
+
+        let drawn = chars.len() <= 4 || !chars.iter().any(|c| c.is_alphabetic());
+
+        let kind = match lines.as_slice() {
+            [] => Kind::Text,
+            _ if numbers => Kind::Numbers,
+            [_] => Kind::Text,
+            _ if drawn && lines.iter().all(|line| line.len() == width && width > 1) => Kind::Grid,
+            _ => Kind::Lines,
+        };
+
+        Self {
+            kind,
+            lines: lines.len(),
+            width,
+        }
+    }
+}
+
+// the description starts with `--- Day 7: Some Assembly Required ---`
+pub fn title(description: &str) -> Option<String> {
+    let line = description.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = line.split_once(": ")?;
+
+    Some(title.trim().trim_end_matches('-').trim().to_string()).filter(|title| !title.is_empty())
+}
+
+// the configured templates dir takes precedence over the builtin templates
+fn source(dir: Option<&Path>, variant: Option<&str>, name: &str) -> Option<String> {
+    let names = match variant {
+        Some(variant) => vec![format!("{}/{}", variant, name), name.to_string()],
+        None => vec![name.to_string()],
+    };
+
+    names.iter().find_map(|name| {
+        dir.and_then(|dir| std::fs::read_to_string(dir.join(name)).ok())
+            .or_else(|| {
+                BUILTIN
+                    .iter()
+                    .find(|(builtin, _)| builtin == name)
+                    .map(|(_, template)| template.to_string())
+            })
+    })
+}
+
+pub fn variants() -> Result<Vec<String>, String> {
+    let mut variants = BUILTIN
+        .iter()
+        .filter_map(|(name, _)| name.split_once('/').map(|(variant, _)| variant.to_string()))
+        .collect::<Vec<_>>();
+
+    if let Some(dir) = &config::get()?.templates.dir {
+        let entries = std::fs::read_dir(dir).into_iter().flatten().flatten();
+
+        variants.extend(
+            entries
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string()),
+        );
+    }
+
+    variants.push("default".to_string());
+    variants.sort();
+    variants.dedup();
+
+    Ok(variants)
 }
 
-pub fn example_template() -> String {
-    PUZZLE_EXAMPLE_TEMPLATE.to_string()
+pub fn validate(variant: Option<&str>) -> Result<(), String> {
+    let variants = variants()?;
+
+    match variant {
+        Some(variant) if !variants.iter().any(|known| known == variant) => Err(format!(
+            "unknown template: {}, available: {}",
+            variant,
+            variants.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn render(variant: Option<&str>, name: &str, context: &Context) -> Result<String, String> {
+    let dir = config::get()?.templates.dir.clone();
+    let variant = variant
+        .filter(|variant| *variant != "default")
+        .map(str::to_string);
+
+    let mut environment = minijinja::Environment::new();
+
+    environment.set_keep_trailing_newline(true);
+    environment.set_trim_blocks(true);
+    environment.set_lstrip_blocks(true);
+    environment.set_undefined_behavior(minijinja::UndefinedBehavior::Strict);
+    environment.set_loader(move |name| Ok(source(dir.as_deref(), variant.as_deref(), name)));
+
+    let template = environment
+        .get_template(name)
+        .map_err(|err| format!("failed to load template {}: {}", name, err))?;
+
+    template
+        .render(context)
+        .map_err(|err| format!("failed to render template {}: {}", name, err))
+}
+
+pub fn bin_template(variant: Option<&str>, context: &Context) -> Result<String, String> {
+    render(variant, "bin.txt", context)
+}
+
+pub fn solution_template(variant: Option<&str>, context: &Context) -> Result<String, String> {
+    render(variant, "solution.txt", context)
+}
+
+pub fn day_template(variant: Option<&str>, context: &Context) -> Result<String, String> {
+    render(variant, "day.txt", context)
+}
+
+pub fn mod_template(variant: Option<&str>, context: &Context) -> Result<String, String> {
+    render(variant, "mod.txt", context)
+}
+
+pub fn example_template(variant: Option<&str>, context: &Context) -> Result<String, String> {
+    render(variant, "example.txt", context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hints_tell_the_shape_of_the_input() {
+        assert_eq!(Hints::new("").kind, Kind::Text);
+        assert_eq!(Hints::new("((())").kind, Kind::Text);
+        assert_eq!(Hints::new("1 2\n3 4\n").kind, Kind::Numbers);
+        assert_eq!(Hints::new("#..\n.#.\n..#\n").kind, Kind::Grid);
+        assert_eq!(Hints::new("123 -> x\nNOT x -> h\n").kind, Kind::Lines);
+        assert_eq!(Hints::new("0.2#\n#7.5\n").kind, Kind::Grid);
+        assert_eq!(Hints::new("XMAS\nSAMX\n").kind, Kind::Grid);
+        assert_eq!(
+            Hints::new("ugknbfddgicrmopn\njchzalrnumimnmhp\n").kind,
+            Kind::Lines
+        );
+
+        let hints = Hints::new("#..\n.#.\n");
+
+        assert_eq!((hints.lines, hints.width), (2, 3));
+        assert_eq!(
+            serde_json::to_value(&hints).expect("failed to serialize hints")["kind"],
+            "grid"
+        );
+    }
 }
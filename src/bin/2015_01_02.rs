@@ -1,4 +1,4 @@
-use aoc::puzzles::_2015_01_02::solution::Puzzle;
+use aoc::puzzles::y2015::d01::part2::solution::Puzzle;
 use aoc::template::Runner;
 
 fn main() {
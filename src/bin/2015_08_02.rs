@@ -1,4 +1,4 @@
-use aoc::puzzles::_2015_08_02::solution::Puzzle;
+use aoc::puzzles::y2015::d08::part2::solution::Puzzle;
 use aoc::template::Runner;
 
 fn main() {
@@ -13,4 +13,4 @@ mod tests {
     fn test() {
         Runner::examples(Puzzle);
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use aoc::puzzles::_2015_09_01::solution::Puzzle;
+use aoc::puzzles::y2015::d09::solution::Part1 as Puzzle;
 use aoc::template::Runner;
 
 fn main() {
@@ -13,4 +13,4 @@ mod tests {
     fn test() {
         Runner::examples(Puzzle);
     }
-}
\ No newline at end of file
+}
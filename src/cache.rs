@@ -0,0 +1,61 @@
+use crate::template::Puzzle;
+
+pub fn validate(input: &str) -> Result<(), String> {
+    let start = input
+        .trim_start()
+        .chars()
+        .take(16)
+        .collect::<String>()
+        .to_lowercase();
+
+    if start.is_empty() {
+        return Err("input is empty".to_string());
+    }
+
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err("input is an html page, the session cookie may be invalid".to_string());
+    }
+
+    if input.contains("Puzzle inputs differ by user") {
+        return Err("input is a login notice, the session cookie may be invalid".to_string());
+    }
+
+    Ok(())
+}
+
+pub fn input(puzzle: &Puzzle) -> Result<Option<String>, String> {
+    let input = match std::fs::read_to_string(&puzzle.input_path) {
+        Ok(input) => input,
+        Err(_) => return Ok(None),
+    };
+
+    validate(&input)?;
+
+    Ok(Some(input))
+}
+
+pub fn save(puzzle: &Puzzle, input: &str) -> Result<(), String> {
+    validate(input)?;
+
+    if let Some(dir) = std::path::Path::new(&puzzle.input_path).parent() {
+        std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+
+    std::fs::write(&puzzle.input_path, input).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn login_pages_are_not_inputs() {
+        assert_eq!(validate("((())\n"), Ok(()));
+        assert!(validate("  \n").is_err());
+        assert!(validate("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use aoc_client::SubmissionOutcome;
+use reqwest::blocking::Client;
+use reqwest::header::{COOKIE, USER_AGENT};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Session;
+use crate::template::Puzzle;
+
+const SESSION_COOKIE_FILE: &str = "adventofcode.session";
+const HIDDEN_SESSION_COOKIE_FILE: &str = ".adventofcode.session";
+const USER_AGENT_VALUE: &str = "github.com/saud-alnasser/advent-of-code";
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug)]
+pub struct Submission {
+    pub outcome: SubmissionOutcome,
+    pub hint: Option<Hint>,
+    pub wait: Option<Duration>,
+}
+
+impl Submission {
+    pub fn parse(html: &str) -> Result<Self, String> {
+        // the rest of the page, i.e sponsors, can say anything
+        let html = article(html);
+
+        let outcome = if html.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if html.contains("That's not the right answer") {
+            SubmissionOutcome::Incorrect
+        } else if html.contains("You gave an answer too recently") {
+            SubmissionOutcome::Wait
+        } else if html.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::WrongLevel
+        } else {
+            return Err("unrecognized submission response".to_string());
+        };
+
+        let hint = if html.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        let wait = match outcome {
+            SubmissionOutcome::Wait => Some(left_to_wait(html).unwrap_or(DEFAULT_WAIT)),
+            SubmissionOutcome::Incorrect => Some(wait_before_retry(html).unwrap_or(DEFAULT_WAIT)),
+            _ => None,
+        };
+
+        Ok(Self {
+            outcome,
+            hint,
+            wait,
+        })
+    }
+}
+
+// the response to a submission is the only article of the page
+fn article(html: &str) -> &str {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+
+    &html[start..end]
+}
+
+// parses "You have 1m 23s left to wait."
+fn left_to_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+
+    for token in html[start..end].split_whitespace() {
+        let (value, scale) = [("h", 3600), ("m", 60), ("s", 1)]
+            .iter()
+            .find_map(|(unit, scale)| token.strip_suffix(unit).map(|value| (value, scale)))?;
+
+        secs += value.parse::<u64>().ok()? * scale;
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+// parses "please wait one minute before trying again" or "wait 5 minutes before trying again"
+fn wait_before_retry(html: &str) -> Option<Duration> {
+    let start = html.to_ascii_lowercase().find("wait ")? + "wait ".len();
+    let mut words = html[start..].split_whitespace();
+
+    let value = match words.next()? {
+        "one" => 1,
+        value => value.parse::<u64>().ok()?,
+    };
+
+    match words.next()? {
+        "minute" | "minutes" => Some(Duration::from_secs(value * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+pub fn session_cookie(session: &Session) -> Result<String, String> {
+    if let Ok(cookie) = std::env::var(&session.env) {
+        if !cookie.trim().is_empty() {
+            return Ok(cookie.trim().to_string());
+        }
+    }
+
+    let path = session
+        .file
+        .clone()
+        .filter(|file| file.exists())
+        .or_else(|| {
+            dirs::home_dir()
+                .map(|dir| dir.join(HIDDEN_SESSION_COOKIE_FILE))
+                .filter(|file| file.exists())
+        })
+        .or_else(|| {
+            dirs::config_dir()
+                .map(|dir| dir.join(SESSION_COOKIE_FILE))
+                .filter(|file| file.exists())
+        })
+        .ok_or("session cookie file not found in config, home or config directory")?;
+
+    let cookie = std::fs::read_to_string(&path)
+        .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+
+    Ok(cookie.trim().to_string())
+}
+
+pub fn submit(puzzle: &Puzzle, answer: &str, session: &Session) -> Result<Submission, String> {
+    let cookie = session_cookie(session)?;
+
+    let year: u16 = puzzle.year.parse().map_err(|_| "invalid year")?;
+    let day: u8 = puzzle.day.parse().map_err(|_| "invalid day")?;
+    let part: u8 = puzzle.part.parse().map_err(|_| "invalid part")?;
+
+    let response = Client::new()
+        .post(format!(
+            "https://adventofcode.com/{}/day/{}/answer",
+            year, day
+        ))
+        .header(COOKIE, format!("session={}", cookie))
+        .header(USER_AGENT, USER_AGENT_VALUE)
+        .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+        .send()
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .map_err(|err| err.to_string())?;
+
+    Submission::parse(&response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en-us">
+<head>
+<meta charset="utf-8"/>
+<title>Day 1 - Advent of Code 2015</title>
+</head><!--
+Oh, hello!  Funny seeing you here.
+-->
+<body>
+<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1></div></header>
+<div id="sidebar">
+<div id="sponsor"><div class="quiet">Our <a href="/2015/sponsors">sponsors</a> help make Advent of Code possible:</div><div class="sponsor"><a href="https://example.com" target="_blank">Acme</a> - Why wait 3 minutes for a deploy?</div></div>
+</div><!--/sidebar-->
+
+<main>
+{}
+</main>
+</body>
+</html>"#,
+            article
+        )
+    }
+
+    #[test]
+    fn parses_a_wrong_answer_with_its_cooldown() {
+        let html = page(
+            r#"<article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2015/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2015/day/1">[Return to Day 1]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert!(matches!(submission.outcome, SubmissionOutcome::Incorrect));
+        assert_eq!(submission.hint, Some(Hint::TooLow));
+        assert_eq!(submission.wait, Some(Duration::from_secs(60)));
+
+        let html = page(
+            r#"<article><p>That's not the right answer; your answer is too high.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again. <a href="/2015/day/1">[Return to Day 1]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert_eq!(submission.hint, Some(Hint::TooHigh));
+        assert_eq!(submission.wait, Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn wrong_answer_without_a_readable_cooldown_waits_the_default() {
+        let html = page(
+            r#"<article><p>That's not the right answer.  Please try again later. <a href="/2015/day/1">[Return to Day 1]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert_eq!(submission.hint, None);
+        assert_eq!(submission.wait, Some(DEFAULT_WAIT));
+    }
+
+    #[test]
+    fn parses_the_time_left_to_wait() {
+        let html = page(
+            r#"<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 35s left to wait. <a href="/2015/day/1">[Return to Day 1]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert!(matches!(submission.outcome, SubmissionOutcome::Wait));
+        assert_eq!(submission.wait, Some(Duration::from_secs(95)));
+        assert_eq!(left_to_wait("You have 5€ left to wait"), None);
+    }
+
+    #[test]
+    fn parses_right_answers_and_wrong_levels() {
+        let html = page(
+            r#"<article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to powering the weather machine. <a href="/2015/day/1#part2">[Continue to Part Two]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert!(matches!(submission.outcome, SubmissionOutcome::Correct));
+        assert_eq!(submission.wait, None);
+
+        let html = page(
+            r#"<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2015/day/1">[Return to Day 1]</a></p></article>"#,
+        );
+
+        let submission = Submission::parse(&html).expect("failed to parse submission");
+
+        assert!(matches!(submission.outcome, SubmissionOutcome::WrongLevel));
+        assert!(Submission::parse(&page("<article><p>Not found</p></article>")).is_err());
+    }
+}
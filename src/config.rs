@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+const CONFIG_ENV_VAR: &str = "AOC_CONFIG";
+const CONFIG_FILE: &str = "aoc.toml";
+
+// puzzle modules and bins are compiled into the crate, so unlike data they can't be moved
+pub const PUZZLES_DIR: &str = "src/puzzles";
+pub const BINS_DIR: &str = "src/bin";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<u16>,
+    pub profile: Option<String>,
+    pub session: Session,
+    pub profiles: BTreeMap<String, Session>,
+    pub paths: Paths,
+    pub submit: Submit,
+    pub templates: Templates,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Session {
+    pub env: String,
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub inputs: String,
+    pub examples: String,
+    pub answers: String,
+    pub metadata: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Submit {
+    pub auto: bool,
+    pub wait: bool,
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Templates {
+    pub dir: Option<PathBuf>,
+    pub variant: Option<String>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            env: "ADVENT_OF_CODE_SESSION".to_string(),
+            file: None,
+        }
+    }
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            inputs: "data/inputs".to_string(),
+            examples: "data/examples".to_string(),
+            answers: "data/answers".to_string(),
+            metadata: "data/metadata".to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var(CONFIG_ENV_VAR).unwrap_or_else(|_| CONFIG_FILE.to_string());
+
+        let mut config = match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str::<Config>(&content)
+                .map_err(|err| format!("unable to parse {}: {}", path, err))?,
+            Err(_) => Config::default(),
+        };
+
+        config.overrides()?;
+
+        Ok(config)
+    }
+
+    fn overrides(&mut self) -> Result<(), String> {
+        fn var(name: &str) -> Option<String> {
+            std::env::var(name)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        }
+
+        fn flag(name: &str) -> Result<Option<bool>, String> {
+            var(name)
+                .map(|value| match value.as_str() {
+                    "1" | "true" => Ok(true),
+                    "0" | "false" => Ok(false),
+                    _ => Err(format!("{} must be true or false", name)),
+                })
+                .transpose()
+        }
+
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = Some(year.parse().map_err(|_| "AOC_YEAR must be a number")?);
+        }
+
+        if let Some(profile) = var("AOC_PROFILE") {
+            self.profile = Some(profile);
+        }
+
+        if let Some(env) = var("AOC_SESSION_ENV") {
+            self.session.env = env;
+        }
+
+        if let Some(file) = var("AOC_SESSION_FILE") {
+            self.session.file = Some(file.into());
+        }
+
+        for (name, path) in [
+            ("AOC_PATHS_INPUTS", &mut self.paths.inputs),
+            ("AOC_PATHS_EXAMPLES", &mut self.paths.examples),
+            ("AOC_PATHS_ANSWERS", &mut self.paths.answers),
+            ("AOC_PATHS_METADATA", &mut self.paths.metadata),
+        ] {
+            if let Some(value) = var(name) {
+                *path = value;
+            }
+        }
+
+        for (name, value) in [
+            ("AOC_SUBMIT_AUTO", &mut self.submit.auto),
+            ("AOC_SUBMIT_WAIT", &mut self.submit.wait),
+            ("AOC_SUBMIT_FORCE", &mut self.submit.force),
+        ] {
+            if let Some(flag) = flag(name)? {
+                *value = flag;
+            }
+        }
+
+        if let Some(dir) = var("AOC_TEMPLATES_DIR") {
+            self.templates.dir = Some(dir.into());
+        }
+
+        if let Some(variant) = var("AOC_TEMPLATES_VARIANT") {
+            self.templates.variant = Some(variant);
+        }
+
+        for profile in self.profile.iter().chain(self.profiles.keys()) {
+            validate_profile(profile)?;
+        }
+
+        if let Some(profile) = &self.profile {
+            if !self.profiles.contains_key(profile) {
+                return Err(format!("profile is not configured: {}", profile));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn session(&self, profile: Option<&str>) -> &Session {
+        profile
+            .and_then(|profile| self.profiles.get(profile))
+            .unwrap_or(&self.session)
+    }
+}
+
+// a profile name ends up in paths, anything but a plain name could escape the data dirs
+pub fn validate_profile(profile: &str) -> Result<(), String> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    match valid {
+        true => Ok(()),
+        false => Err(format!(
+            "invalid profile name: {:?}, only letters, digits, _ and - are allowed",
+            profile
+        )),
+    }
+}
+
+// data of a profile lives in its own dir under each data root
+pub fn profile_dir(root: &str, profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("{}/{}", root, profile),
+        None => root.to_string(),
+    }
+}
+
+// loaded once, an invalid config is reported to every caller instead of panicking
+pub fn get() -> Result<&'static Config, String> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let config = Config::load()?;
+
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_names_are_plain() {
+        for profile in ["alice", "bob_2", "work-account", "A1"] {
+            assert_eq!(validate_profile(profile), Ok(()));
+        }
+
+        for profile in ["", "../alice", "alice/bob", "al ice", ".", "élise"] {
+            assert!(validate_profile(profile).is_err(), "{}", profile);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Input(String),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // builds an error pointing at `token`, which must be a slice of `source`
+    pub fn at(line: usize, source: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+
+        match offset <= source.len() {
+            true => Self::new(line, offset + 1, message),
+            false => Self::new(line, 1, message),
+        }
+    }
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "solve error: {}", self.message)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(err) => write!(f, "input error: {}", err),
+            Self::Parse(err) => write!(f, "{}", err),
+            Self::Solve(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+impl std::error::Error for SolveError {}
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Self {
+        Self::Solve(err)
+    }
+}
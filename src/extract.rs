@@ -0,0 +1,305 @@
+use crate::template::{Example, Params};
+
+enum Chunk {
+    Block(String),
+    Text(String),
+}
+
+pub fn extract(description: &str, part: u8) -> Vec<Example> {
+    let sections = sections(description);
+
+    let chunks = match sections.get(usize::from(part).saturating_sub(1)) {
+        Some(chunks) => chunks,
+        None => return vec![],
+    };
+
+    let mut examples = vec![];
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        if let Chunk::Block(sample) = chunk {
+            let mut found = vec![];
+
+            // a sample is often followed by an illustration before its answer is stated
+            for chunk in chunks[i + 1..].iter() {
+                match chunk {
+                    Chunk::Text(text) => found.extend(spans(text)),
+                    Chunk::Block(_) if !found.is_empty() => break,
+                    Chunk::Block(_) => {}
+                }
+            }
+
+            examples.push(candidate(sample, answer(&found)));
+        }
+    }
+
+    // later parts usually reuse the samples of the first part ("given the example above")
+    if examples.is_empty() && part > 1 {
+        let sample = sections[0].iter().find_map(|chunk| match chunk {
+            Chunk::Block(sample) => Some(sample),
+            Chunk::Text(_) => None,
+        });
+
+        let spans = chunks
+            .iter()
+            .flat_map(|chunk| match chunk {
+                Chunk::Text(text) => spans(text),
+                Chunk::Block(_) => vec![],
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(sample) = sample {
+            examples.push(candidate(sample, answer(&spans)));
+        }
+    }
+
+    examples
+}
+
+fn candidate(sample: &str, expected: Option<String>) -> Example {
+    Example {
+        name: None,
+        part: None,
+        sample: sample.to_string(),
+        expected,
+        pending: true,
+        params: Params::default(),
+    }
+}
+
+fn sections(description: &str) -> Vec<Vec<Chunk>> {
+    let mut sections = vec![vec![]];
+    let mut text = String::new();
+    let mut lines = description.lines();
+
+    fn flush(sections: &mut [Vec<Chunk>], text: &mut String) {
+        if !text.trim().is_empty() {
+            if let Some(section) = sections.last_mut() {
+                section.push(Chunk::Text(std::mem::take(text)));
+            }
+        }
+
+        text.clear();
+    }
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if trimmed.contains("--- Part Two ---") {
+            flush(&mut sections, &mut text);
+            sections.push(vec![]);
+            continue;
+        }
+
+        let html = trimmed.starts_with("<pre><code>");
+
+        if !html && !trimmed.starts_with("```") {
+            // the answers of solved parts are not part of the examples
+            if !trimmed.starts_with("Your puzzle answer was") {
+                text.push_str(line);
+                text.push('\n');
+            }
+
+            continue;
+        }
+
+        flush(&mut sections, &mut text);
+
+        let mut block = vec![];
+        let mut line = match html {
+            true => Some(&trimmed["<pre><code>".len()..]),
+            false => lines.next(),
+        };
+
+        while let Some(current) = line {
+            if html {
+                if let Some(end) = current.find("</code></pre>") {
+                    block.push(unescape(&current[..end]));
+                    break;
+                }
+
+                block.push(unescape(current));
+            } else {
+                if current.trim().starts_with("```") {
+                    break;
+                }
+
+                block.push(current.to_string());
+            }
+
+            line = lines.next();
+        }
+
+        let sample = block.join("\n").trim_matches('\n').to_string();
+
+        if let Some(section) = sections.last_mut() {
+            section.push(Chunk::Block(sample));
+        }
+    }
+
+    flush(&mut sections, &mut text);
+
+    sections
+}
+
+fn spans(text: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut rest = text;
+
+    loop {
+        let next = [("`", "`"), ("<em>", "</em>"), ("<code>", "</code>")]
+            .iter()
+            .filter_map(|&(open, close)| rest.find(open).map(|i| (i, open, close)))
+            .min_by_key(|&(i, _, _)| i);
+
+        let Some((i, open, close)) = next else {
+            break;
+        };
+
+        let after = &rest[i + open.len()..];
+
+        let Some(end) = after.find(close) else {
+            break;
+        };
+
+        let span = unescape(&after[..end]);
+
+        if !span.trim().is_empty() {
+            spans.push(span.trim().to_string());
+        }
+
+        rest = &after[end + close.len()..];
+    }
+
+    spans
+}
+
+// prefer the last number mentioned after a sample, puzzles mostly state the answer last
+fn answer(spans: &[String]) -> Option<String> {
+    spans
+        .iter()
+        .rev()
+        .find(|span| span.parse::<i64>().is_ok())
+        .or_else(|| spans.last())
+        .cloned()
+}
+
+fn unescape(html: &str) -> String {
+    html.replace("<em>", "")
+        .replace("</em>", "")
+        .replace("<code>", "")
+        .replace("</code>", "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2022 day 1, as served by the site
+    const CALORIES: &str = r#"<h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example, suppose the Elves finish writing their items' Calories and end up with the following list:</p>
+<pre><code>1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+</code></pre>
+<p>This list represents the Calories of the food carried by five Elves:</p>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code>, <code>2000</code>, and <code>3000</code> Calories, a total of <code><em>6000</em></code> Calories.</li>
+<li>The fourth Elf is carrying food with <code>7000</code>, <code>8000</code>, and <code>9000</code> Calories, a total of <code><em>24000</em></code> Calories.</li>
+<li>The fifth Elf is carrying one food item with <code>10000</code> Calories.</li>
+</ul>
+<p>In the example above, this is <em>24000</em> (carried by the fourth Elf).</p>
+<p>Find the Elf carrying the most Calories. <em>How many total Calories is that Elf carrying?</em></p>
+<h2 id="part2">--- Part Two ---</h2>
+<p>In the example above, the top three Elves are the fourth Elf (with <code>24000</code> Calories), then the third Elf (with <code>11000</code> Calories), then the fifth Elf (with <code>10000</code> Calories). The sum of the Calories carried by these three elves is <code><em>45000</em></code>.</p>
+<p>Find the top three Elves carrying the most Calories. <em>How many Calories are those Elves carrying in total?</em></p>
+"#;
+
+    // 2015 day 7, once converted to markdown and with the first part solved
+    const CIRCUIT: &str = r#"\--- Day 7: Some Assembly Required ---
+----------
+
+For example, here is a simple circuit:
+
+```
+123 -> x
+456 -> y
+x AND y -> d
+
+```
+
+After it is run, wire `d` carries the signal `72`.
+
+Your puzzle answer was `46065`.
+
+\--- Part Two ---
+----------
+
+Now, take the signal you got on wire `a`, override wire `b` to that signal. What new signal is ultimately provided to wire `a`?
+"#;
+
+    #[test]
+    fn answers_follow_their_sample() {
+        let examples = extract(CALORIES, 1);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].sample,
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000"
+        );
+        assert_eq!(examples[0].expected.as_deref(), Some("24000"));
+        assert!(examples[0].pending);
+    }
+
+    #[test]
+    fn later_parts_reuse_the_first_sample() {
+        let examples = extract(CALORIES, 2);
+
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].sample.starts_with("1000\n2000"));
+        assert_eq!(examples[0].expected.as_deref(), Some("45000"));
+    }
+
+    #[test]
+    fn solved_answers_are_not_examples() {
+        let examples = extract(CIRCUIT, 1);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].sample, "123 -> x\n456 -> y\nx AND y -> d");
+        assert_eq!(examples[0].expected.as_deref(), Some("72"));
+
+        // no number is stated for the second part, the last span is the best guess
+        assert_eq!(extract(CIRCUIT, 2)[0].expected.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn html_samples_are_unescaped() {
+        let examples = extract(
+            "<pre><code>x AND y -&gt; d\n&quot;a&quot;</code></pre>\n",
+            1,
+        );
+
+        assert_eq!(examples[0].sample, "x AND y -> d\n\"a\"");
+        assert_eq!(examples[0].expected, None);
+    }
+
+    #[test]
+    fn missing_parts_have_no_examples() {
+        assert!(extract(CALORIES, 3).is_empty());
+    }
+}
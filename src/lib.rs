@@ -1,3 +1,13 @@
+pub mod answer;
+pub mod answers;
 pub mod assets;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod extract;
+pub mod plan;
+pub mod provider;
 pub mod puzzles;
+pub mod registry;
 pub mod template;
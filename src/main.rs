@@ -1,49 +1,341 @@
-use aoc::assets;
-use aoc::template::Puzzle;
-use aoc_client::AocClient;
-use clap::{arg, command, Parser, Subcommand};
+use std::io::Write;
+
+use aoc::answers::Answers;
+use aoc::config::Config;
+use aoc::plan::Plan;
+use aoc::template::{
+    Example, Metadata, Puzzle, Run, Runner, Source, SubmitOptions, Target, Verdict,
+};
+use aoc::{assets, cache, config, extract, provider, registry};
+use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
-struct CLI {
+struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum Commands {
-    #[command(about = "scaffolds a new puzzle by creating all necessary files")]
+    #[command(about = "scaffolds a new puzzle, day or event by creating all necessary files")]
     Scaffold {
-        /// puzzle to scaffold it's necessary files
-        #[arg(value_parser = Puzzle::parse)]
-        puzzle: Puzzle,
+        /// puzzle, day or event to scaffold it's necessary files
+        #[arg(value_parser = Target::event)]
+        puzzle: Target,
         /// force the creation of the files even if they already exist
         #[arg(long)]
         force: bool,
+        /// also create a standalone `src/bin` file for the puzzle
+        #[arg(long)]
+        bin: bool,
+        /// scaffold a day-level module solving both parts from a single parse
+        #[arg(long, conflicts_with = "bin")]
+        day_level: bool,
+        /// only create the source files, without downloading the input or description
+        #[arg(long)]
+        offline: bool,
+        /// download and print the files that would be written without touching anything
+        #[arg(long)]
+        dry_run: bool,
+        /// template variant to scaffold with, i.e grid or lines
+        #[arg(long)]
+        template: Option<String>,
     },
-    #[command(about = "runs a puzzle against the input data")]
+    #[command(about = "runs a puzzle against the input data without submitting")]
     Solve {
         // puzzle to run it's solution against the input data
+        #[arg(value_parser = Target::parse)]
+        puzzle: Target,
+        /// run against the given file instead of the puzzle input
+        #[arg(long, conflicts_with_all = ["stdin", "inline"])]
+        input: Option<String>,
+        /// run against the input piped through stdin
+        #[arg(long, conflicts_with = "inline")]
+        stdin: bool,
+        /// run against the given string
+        #[arg(long)]
+        inline: Option<String>,
+    },
+    #[command(about = "runs a puzzle against the input data and submits the answer")]
+    Submit {
+        // puzzle to run it's solution against the input data and submit
         #[arg(value_parser = Puzzle::parse)]
         puzzle: Puzzle,
+        /// wait for an active submission cooldown instead of refusing to submit
+        #[arg(long)]
+        wait: bool,
+        /// submit even if the answer looks suspicious (negative, zero, empty)
+        #[arg(long)]
+        force: bool,
+        /// scaffold the second part once the first part is accepted
+        #[arg(long)]
+        scaffold: bool,
     },
     #[command(about = "runs a puzzle against the examples")]
     Examples {
         // puzzle to run it's solution against the examples
-        #[arg(value_parser = Puzzle::parse)]
-        puzzle: Puzzle,
+        #[arg(value_parser = Target::parse)]
+        puzzle: Target,
+    },
+    #[command(about = "extracts candidate examples from the puzzle description")]
+    Extract {
+        // puzzle to extract the examples of
+        #[arg(value_parser = Target::parse)]
+        puzzle: Target,
+        /// accept or reject each candidate instead of writing them all as pending
+        #[arg(long)]
+        interactive: bool,
+        /// overwrite the examples file even if it already has examples
+        #[arg(long)]
+        force: bool,
+    },
+    #[command(about = "runs every registered puzzle against the input data")]
+    RunAll {
+        /// only run puzzles of the given event year
+        #[arg(long)]
+        year: Option<u16>,
+        /// only run puzzles of the given day
+        #[arg(long)]
+        day: Option<u8>,
+        /// only run puzzles of the given part
+        #[arg(long)]
+        part: Option<u8>,
+    },
+    #[command(about = "runs a puzzle against the input of every profile")]
+    Compare {
+        // puzzle to run against the inputs of every profile
+        #[arg(value_parser = Target::parse)]
+        puzzle: Target,
+        /// download the inputs missing from a profile
+        #[arg(long)]
+        fetch: bool,
+    },
+    #[command(about = "recomputes every puzzle and compares it with the accepted answers")]
+    Verify,
+    #[command(about = "shows or edits the metadata of a puzzle day")]
+    Meta {
+        // day to show or edit the metadata of
+        #[arg(value_parser = Target::parse)]
+        puzzle: Target,
+        /// add a tag, i.e grid, graph or simulation
+        #[arg(long)]
+        tag: Vec<String>,
+        /// remove a tag
+        #[arg(long)]
+        untag: Vec<String>,
+        /// how hard the puzzle was
+        #[arg(long)]
+        difficulty: Option<String>,
+        /// notes on the puzzle, i.e the approach that worked
+        #[arg(long)]
+        notes: Option<String>,
+    },
+    #[command(about = "lists puzzles by their metadata")]
+    List {
+        /// only list puzzles of the given event year
+        #[arg(long)]
+        year: Option<u16>,
+        /// only list puzzles with the given tag
+        #[arg(long)]
+        tag: Vec<String>,
+        /// only list puzzles with at least the given number of stars
+        #[arg(long)]
+        stars: Option<usize>,
+        /// search the titles, tags, difficulty and notes
+        query: Option<String>,
+    },
+    #[command(about = "moves flat `_YYYY_DD_PP` puzzle modules into year and day modules")]
+    Migrate {
+        /// print the files that would be moved without touching anything
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
+#[derive(Debug, Default, Clone)]
+struct ScaffoldOptions {
+    force: bool,
+    bin: bool,
+    day_level: bool,
+    offline: bool,
+    dry_run: bool,
+    template: Option<String>,
+}
+
+// the config is loaded and checked once on startup, before any command runs
+fn settings() -> &'static Config {
+    config::get().expect("config is loaded on startup")
+}
+
 fn main() {
     tracing_subscriber::fmt::init();
 
-    let cli = CLI::parse();
+    if let Err(err) = config::get() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+
+    let cli = Cli::parse();
 
     match cli.command {
-        Some(Commands::Scaffold { puzzle, force }) => scaffold(puzzle, force),
-        Some(Commands::Solve { puzzle }) => solve(puzzle),
-        Some(Commands::Examples { puzzle }) => examples(puzzle),
+        Some(Commands::Scaffold {
+            puzzle,
+            force,
+            bin,
+            day_level,
+            offline,
+            dry_run,
+            template,
+        }) => {
+            let options = ScaffoldOptions {
+                force,
+                bin,
+                day_level,
+                offline,
+                dry_run,
+                template: template.or_else(|| settings().templates.variant.clone()),
+            };
+
+            if let Err(err) = scaffold(puzzle.puzzles, &options) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Solve {
+            puzzle,
+            input,
+            stdin,
+            inline,
+        }) => {
+            let source = match (input, stdin, inline) {
+                (Some(path), _, _) => Source::File(path),
+                // stdin can only be read once, a whole day shares it between both parts
+                (_, true, _) if puzzle.puzzles.len() > 1 => Source::Inline(
+                    std::io::read_to_string(std::io::stdin()).expect("failed to read stdin"),
+                ),
+                (_, true, _) => Source::Stdin,
+                (_, _, Some(input)) => Source::Inline(input),
+                _ => Source::Puzzle,
+            };
+
+            let auto = settings().submit.auto && matches!(source, Source::Puzzle);
+
+            match find(&puzzle.puzzles[0]).day {
+                // a whole day of a day-level module parses its input once for both parts
+                Some(day) if puzzle.puzzles.len() > 1 && !auto => {
+                    if let Err(err) = Runner::solve_day(&puzzle.puzzles, day, &source) {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    }
+                }
+                _ => {
+                    let failed = puzzle
+                        .puzzles
+                        .into_iter()
+                        .filter_map(|puzzle| solve(puzzle, source.clone()).err())
+                        .inspect(|err| eprintln!("{}", err))
+                        .count();
+
+                    if failed > 0 {
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        Some(Commands::Submit {
+            puzzle,
+            wait,
+            force,
+            scaffold,
+        }) => {
+            let options = SubmitOptions {
+                wait: wait || settings().submit.wait,
+                force: force || settings().submit.force,
+            };
+
+            if let Err(err) = submit(puzzle, options, scaffold) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Examples { puzzle }) => {
+            let failed = puzzle
+                .puzzles
+                .into_iter()
+                .filter(|puzzle| match examples(puzzle) {
+                    Ok(verdict) => verdict != Verdict::Passed,
+                    Err(err) => {
+                        eprintln!("puzzle[{}]: {}", puzzle, err);
+                        true
+                    }
+                })
+                .count();
+
+            if failed > 0 {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Extract {
+            puzzle,
+            interactive,
+            force,
+        }) => {
+            let failed = puzzle
+                .puzzles
+                .into_iter()
+                .filter(|puzzle| match extract(puzzle, interactive, force) {
+                    Ok(()) => false,
+                    Err(err) => {
+                        eprintln!("puzzle[{}]: {}", puzzle, err);
+                        true
+                    }
+                })
+                .count();
+
+            if failed > 0 {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::RunAll { year, day, part }) => run_all(year, day, part),
+        Some(Commands::Compare { puzzle, fetch }) => {
+            let failed: usize = puzzle
+                .puzzles
+                .into_iter()
+                .map(|puzzle| compare(puzzle, fetch))
+                .sum();
+
+            if failed > 0 {
+                eprintln!("{} profile(s) diverged from their accepted answer", failed);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Verify) => verify(),
+        Some(Commands::Meta {
+            puzzle,
+            tag,
+            untag,
+            difficulty,
+            notes,
+        }) => {
+            if let Err(err) = meta(&puzzle.puzzles[0], tag, untag, difficulty, notes) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::List {
+            year,
+            tag,
+            stars,
+            query,
+        }) => list(year, &tag, stars, query.as_deref()),
+        Some(Commands::Migrate { dry_run }) => {
+            if let Err(err) = migrate(dry_run) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
         None => {
             eprintln!("no valid command provided");
             std::process::exit(1);
@@ -51,96 +343,789 @@ fn main() {
     }
 }
 
-fn scaffold(puzzle: Puzzle, force: bool) {
-    if !force {
-        if std::path::Path::new(&puzzle.dir_path).exists() {
-            eprintln!("puzzle already exists: {}", puzzle.dir_path);
-            return;
+fn scaffold(puzzles: Vec<Puzzle>, options: &ScaffoldOptions) -> Result<(), String> {
+    assets::validate(options.template.as_deref())?;
+
+    let single = puzzles.len() == 1;
+
+    // a day-level module is scaffolded once for both parts of its day
+    let mut puzzles = puzzles;
+
+    if options.day_level {
+        puzzles.dedup_by(|a, b| a.year == b.year && a.day == b.day);
+    }
+
+    let puzzles_mod_path = format!("{}/mod.rs", config::PUZZLES_DIR);
+
+    let mut puzzles_mod_file = std::fs::read_to_string(&puzzles_mod_path)
+        .map_err(|err| format!("failed to read puzzles mod: {}", err))?;
+
+    let mut registered = false;
+    let mut downloads = Downloads::default();
+    let mut plan = Plan::new();
+
+    for puzzle in puzzles.iter() {
+        // a day-level module holds the examples of both parts
+        let parts = match options.day_level {
+            true => Target::day(puzzle)?.puzzles,
+            false => vec![puzzle.clone()],
+        };
+
+        // scaffolding a whole day or event leaves the puzzles already started alone
+        if !single && !options.force && parts.iter().any(scaffolded) {
+            println!("puzzle[{}]: already scaffolded, skipped", puzzle);
+            continue;
         }
 
-        if std::path::Path::new(&puzzle.bin_file_path).exists() {
-            eprintln!("puzzle already exists: {}", puzzle.bin_file_path);
-            return;
+        let source = registry::register(&puzzles_mod_file, puzzle, options.day_level)?;
+
+        // a day that fails to download is skipped, the rest of a whole event is still scaffolded
+        let mut staged = Plan::new();
+
+        match scaffold_puzzle(&mut staged, &mut downloads, puzzle, &parts, options) {
+            Ok(()) => plan.extend(staged),
+            Err(err) if !single => {
+                eprintln!("puzzle[{}]: {}, skipped", puzzle, err);
+                continue;
+            }
+            Err(err) => return Err(err),
         }
 
-        if std::path::Path::new(&puzzle.examples_path).exists() {
-            eprintln!("puzzle file already exists: {}", puzzle.examples_path);
-            return;
+        if let Some(source) = source {
+            puzzles_mod_file = source;
+            registered = true;
         }
     }
 
+    // ensure puzzles mod file registers the new puzzles
+    if registered {
+        plan.replace(&puzzles_mod_path, puzzles_mod_file);
+    }
+
+    if options.dry_run {
+        print!("{}", plan);
+        return Ok(());
+    }
+
+    plan.validate(options.force)?;
+    plan.apply()
+}
+
+// inputs and descriptions already fetched, both parts of a day share them
+#[derive(Default)]
+struct Downloads {
+    inputs: Vec<(String, String)>,
+    descriptions: Vec<(String, String)>,
+}
+
+fn scaffolded(puzzle: &Puzzle) -> bool {
+    std::path::Path::new(&puzzle.dir_path).exists()
+        || std::path::Path::new(&format!("{}/solution.rs", puzzle.day_dir_path)).exists()
+}
+
+fn scaffold_puzzle(
+    plan: &mut Plan,
+    downloads: &mut Downloads,
+    puzzle: &Puzzle,
+    parts: &[Puzzle],
+    options: &ScaffoldOptions,
+) -> Result<(), String> {
+    let dir_path = match options.day_level {
+        true => &puzzle.day_dir_path,
+        false => &puzzle.dir_path,
+    };
+
+    let downloaded = downloads
+        .inputs
+        .iter()
+        .find(|(path, _)| *path == puzzle.input_path)
+        .map(|(_, input)| input.clone());
+
+    if let Some(input) = &downloaded {
+        plan.replace(&puzzle.input_path, input.clone());
+    }
+
+    // reuse a valid cached input, both parts of a day share it
+    let mut input = downloaded.or_else(|| match cache::input(puzzle) {
+        Ok(Some(input)) => {
+            println!(
+                "puzzle[{}]: input cached path={}",
+                puzzle, puzzle.input_path
+            );
+            Some(input)
+        }
+        Ok(None) => None,
+        Err(err) => {
+            eprintln!("puzzle[{}]: cached {}", puzzle, err);
+            None
+        }
+    });
+
+    let description_path = format!("{}/description.md", dir_path);
+    let day = format!("{}_{}", puzzle.year, puzzle.day);
+
+    let downloaded = downloads
+        .descriptions
+        .iter()
+        .find(|(id, _)| *id == day)
+        .map(|(_, description)| description.clone());
+
+    // download puzzle description and input before anything is written
+    let description = if let Some(description) = downloaded {
+        plan.replace(&description_path, description.clone());
+
+        description
+    } else if options.offline || !puzzle.unlocked() {
+        if input.is_none() && options.offline {
+            eprintln!("puzzle[{}]: offline, skipped downloading the input", puzzle);
+        } else if input.is_none() && !puzzle.unlocked() {
+            println!("puzzle[{}]: locked, skipped downloading the input", puzzle);
+        }
+
+        std::fs::read_to_string(&description_path).unwrap_or_default()
+    } else {
+        let provider = provider::current()?;
+
+        if input.is_none() {
+            let downloaded = provider
+                .input(puzzle)
+                .map_err(|err| format!("failed to download input: {}", err))?;
+
+            match cache::validate(&downloaded) {
+                Ok(()) => {
+                    plan.replace(&puzzle.input_path, downloaded.clone());
+                    downloads
+                        .inputs
+                        .push((puzzle.input_path.clone(), downloaded.clone()));
+                    input = Some(downloaded);
+                }
+                Err(err) => eprintln!("puzzle[{}]: downloaded {}", puzzle, err),
+            }
+        }
+
+        let description = provider
+            .description(puzzle)
+            .map_err(|err| format!("failed to download puzzle: {}", err))?;
+
+        plan.replace(&description_path, description.clone());
+        downloads.descriptions.push((day, description.clone()));
+
+        description
+    };
+
+    // templates are rendered with the title and the shape of the input when known
+    let context = assets::Context::new(puzzle, &description, input.as_deref());
+    let variant = options.template.as_deref();
+
+    // record what is known about the day, both parts share it
+    let mut metadata = Metadata::load(puzzle)?;
+
+    metadata.refresh(puzzle, context.title.clone())?;
+
+    plan.replace(&puzzle.metadata_path, metadata.to_json());
+
     // create puzzle mod.rs and solution.rs files
-    if !std::path::Path::new(&puzzle.dir_path).exists() {
-        std::fs::create_dir(&puzzle.dir_path).expect("failed to create puzzle dir");
-    }
-
-    std::fs::write(&puzzle.mod_file_path, assets::mod_template(&puzzle))
-        .expect("failed to write mod file");
-
-    std::fs::write(&puzzle.solution_path, assets::solution_template(&puzzle))
-        .expect("failed to write solution file");
-
-    // ensure puzzles mod file contains the new puzzle
-
-    let mut puzzles_mod_file =
-        std::fs::read_to_string("src/puzzles/mod.rs").expect("failed to read puzzles mod");
-
-    if !puzzles_mod_file.contains(&puzzle.dir_name) {
-        puzzles_mod_file.push_str(&format!("\npub mod {};", puzzle.dir_name));
-    }
-
-    std::fs::write("src/puzzles/mod.rs", puzzles_mod_file).expect("failed to write puzzles mod");
-
-    // download puzzle description and input
-    let client = AocClient::builder()
-        .session_cookie_from_default_locations()
-        .expect("failed to get session cookie from default locations on client build")
-        .year(puzzle.year.parse().expect("failed to parse year"))
-        .expect("failed to set year on client build")
-        .day(puzzle.day.parse().expect("failed to parse day"))
-        .expect("failed to set day on client build")
-        .input_filename(&puzzle.input_path)
-        .puzzle_filename(&puzzle.description_path)
-        .overwrite_files(true)
-        .build()
-        .expect("failed to build aoc client");
-
-    client.save_input().expect("failed to save input");
-    client
-        .save_puzzle_markdown()
-        .expect("failed to save puzzle");
-
-    // create examples file (empty)
-    std::fs::write(&puzzle.examples_path, assets::example_template())
-        .expect("failed to write examples file");
-
-    // create puzzle bin_file
-    std::fs::write(&puzzle.bin_file_path, assets::bin_template(&puzzle))
-        .expect("failed to write bin file");
-}
-
-fn solve(puzzle: Puzzle) {
-    std::process::Command::new("cargo")
-        .arg("run")
-        .arg("--bin")
-        .arg(&puzzle.id)
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .spawn()
-        .expect("failed to run cargo")
-        .wait()
-        .expect("failed to run cargo");
-}
-
-fn examples(puzzle: Puzzle) {
-    std::process::Command::new("cargo")
-        .arg("test")
-        .arg("--bin")
-        .arg(&puzzle.id)
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .spawn()
-        .expect("failed to run cargo")
-        .wait()
-        .expect("failed to run cargo");
+    plan.write(
+        format!("{}/mod.rs", dir_path),
+        assets::mod_template(variant, &context)?,
+    );
+
+    let solution = match options.day_level {
+        true => assets::day_template(variant, &context)?,
+        false => assets::solution_template(variant, &context)?,
+    };
+
+    plan.write(format!("{}/solution.rs", dir_path), solution);
+
+    // create examples files (candidates from the description, empty otherwise)
+    for part in parts.iter() {
+        let part_n = part.part.parse().map_err(|_| "invalid part")?;
+        let candidates = extract::extract(&description, part_n);
+
+        let examples = match candidates.is_empty() {
+            true => assets::example_template(variant, &context)?,
+            false => serde_json::to_string_pretty(&candidates).map_err(|err| err.to_string())?,
+        };
+
+        plan.write(&part.examples_path, examples);
+    }
+
+    // create puzzle bin_file (optional, the registry runs puzzles in-process)
+    if options.bin {
+        plan.write(
+            &puzzle.bin_file_path,
+            assets::bin_template(variant, &context)?,
+        );
+    }
+
+    Ok(())
+}
+
+fn meta(
+    puzzle: &Puzzle,
+    tags: Vec<String>,
+    untags: Vec<String>,
+    difficulty: Option<String>,
+    notes: Option<String>,
+) -> Result<(), String> {
+    let mut metadata = Metadata::load(puzzle)?;
+    let before = metadata.clone();
+
+    let title = std::fs::read_to_string(&puzzle.description_path)
+        .or_else(|_| std::fs::read_to_string(format!("{}/description.md", puzzle.day_dir_path)))
+        .ok()
+        .and_then(|description| assets::title(&description));
+
+    metadata.refresh(puzzle, title)?;
+    metadata.tags.extend(tags);
+    metadata.tags.retain(|tag| !untags.contains(tag));
+    metadata.difficulty = difficulty.or(metadata.difficulty);
+    metadata.notes = notes.or(metadata.notes);
+
+    if metadata != before {
+        metadata.save(puzzle)?;
+    }
+
+    let day = format!("{}_{}", puzzle.year, puzzle.day);
+
+    println!("puzzle[{}]: {}", day, describe(&metadata));
+    println!(
+        "puzzle[{}]: url={} released={}",
+        day, metadata.url, metadata.released
+    );
+
+    for (part, progress) in metadata.parts.iter() {
+        println!("puzzle[{}_{}]: {}", day, part, progress);
+    }
+
+    Ok(())
+}
+
+fn list(year: Option<u16>, tags: &[String], stars: Option<usize>, query: Option<&str>) {
+    let root = config::profile_dir(&settings().paths.metadata, settings().profile.as_deref());
+
+    let mut days = std::fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let mut listed = 0;
+
+    for path in days {
+        let Some(day) = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+
+        let Ok(puzzle) = Puzzle::parse(&format!("{}_01", day)) else {
+            continue;
+        };
+
+        // a broken file only hides its own day
+        let metadata = match Metadata::load(&puzzle) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                eprintln!("puzzle[{}]: {}, skipped", day, err);
+                continue;
+            }
+        };
+
+        if year.is_some_and(|year| puzzle.year != format!("{:04}", year))
+            || !tags.iter().all(|tag| metadata.tags.contains(tag))
+            || stars.is_some_and(|stars| metadata.stars() < stars)
+            || query.is_some_and(|query| !metadata.matches(query))
+        {
+            continue;
+        }
+
+        println!("puzzle[{}]: {}", day, describe(&metadata));
+        listed += 1;
+    }
+
+    if listed == 0 {
+        println!("no puzzles found");
+    }
+}
+
+fn describe(metadata: &Metadata) -> String {
+    let mut description = format!(
+        "title={:?} stars={}",
+        metadata.title.as_deref().unwrap_or(""),
+        metadata.stars()
+    );
+
+    if !metadata.tags.is_empty() {
+        let tags = metadata.tags.iter().cloned().collect::<Vec<_>>();
+        description.push_str(&format!(" tags={}", tags.join(",")));
+    }
+
+    if let Some(difficulty) = &metadata.difficulty {
+        description.push_str(&format!(" difficulty={:?}", difficulty));
+    }
+
+    if let Some(notes) = &metadata.notes {
+        description.push_str(&format!(" notes={:?}", notes));
+    }
+
+    description
+}
+
+fn migrate(dry_run: bool) -> Result<(), String> {
+    let puzzles_mod_path = format!("{}/mod.rs", config::PUZZLES_DIR);
+
+    let puzzles_mod_file = std::fs::read_to_string(&puzzles_mod_path)
+        .map_err(|err| format!("failed to read puzzles mod: {}", err))?;
+
+    let modules = registry::legacy(&puzzles_mod_file)?;
+
+    if modules.is_empty() {
+        println!("puzzles are already grouped by year and day");
+        return Ok(());
+    }
+
+    let mut plan = Plan::new();
+
+    for (legacy, module) in modules.iter() {
+        plan.rename(
+            format!("{}/{}", config::PUZZLES_DIR, legacy),
+            format!("{}/{}", config::PUZZLES_DIR, module.replace("::", "/")),
+        );
+    }
+
+    plan.replace(&puzzles_mod_path, registry::normalize(&puzzles_mod_file)?);
+
+    // standalone bins import their puzzle by its module path
+    let mut bins = std::fs::read_dir(config::BINS_DIR)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    bins.sort();
+
+    for bin in bins
+        .iter()
+        .filter(|bin| bin.extension().is_some_and(|ext| ext == "rs"))
+    {
+        let content = std::fs::read_to_string(bin)
+            .map_err(|err| format!("failed to read {}: {}", bin.display(), err))?;
+
+        let migrated = modules
+            .iter()
+            .fold(content.clone(), |content, (legacy, module)| {
+                content.replace(
+                    &format!("puzzles::{}::", legacy),
+                    &format!("puzzles::{}::", module),
+                )
+            });
+
+        if migrated != content {
+            plan.replace(bin, migrated);
+        }
+    }
+
+    if dry_run {
+        print!("{}", plan);
+        return Ok(());
+    }
+
+    plan.validate(false)?;
+    plan.apply()?;
+
+    println!("migrated {} puzzle module(s)", modules.len());
+
+    Ok(())
+}
+
+fn solve(puzzle: Puzzle, source: Source) -> Result<(), String> {
+    let entry = find(&puzzle);
+    let policy = &settings().submit;
+
+    // auto submit only once an example with a known answer passed, only for the puzzle input
+    if policy.auto && matches!(source, Source::Puzzle) && (entry.examples)() == Ok(Verdict::Passed)
+    {
+        let options = SubmitOptions {
+            wait: policy.wait,
+            force: policy.force,
+        };
+
+        return (entry.submit)(&options);
+    }
+
+    (entry.solve)(&source)
+}
+
+fn submit(puzzle: Puzzle, options: SubmitOptions, scaffold_next: bool) -> Result<(), String> {
+    (find(&puzzle).submit)(&options)?;
+
+    if !scaffold_next || puzzle.part != "01" {
+        return Ok(());
+    }
+
+    if Answers::load(&puzzle)?.accepted(&puzzle).is_none() {
+        return Ok(());
+    }
+
+    // the last day of an event has no second part
+    let Ok(second) = Puzzle::parse(&format!("{}_{}_02", puzzle.year, puzzle.day)) else {
+        return Ok(());
+    };
+
+    // day-level modules and already scaffolded parts have nothing to add
+    if registry::find(&second.id).is_some() || std::path::Path::new(&second.dir_path).exists() {
+        return Ok(());
+    }
+
+    scaffold(vec![second], &ScaffoldOptions::default())
+}
+
+fn examples(puzzle: &Puzzle) -> Result<Verdict, String> {
+    (find(puzzle).examples)()
+}
+
+fn extract(puzzle: &Puzzle, interactive: bool, force: bool) -> Result<(), String> {
+    let description = [
+        &puzzle.description_path,
+        &format!("{}/description.md", puzzle.day_dir_path),
+    ]
+    .iter()
+    .find_map(|path| std::fs::read_to_string(path).ok());
+
+    let description = description.ok_or("missing description")?;
+    let part = puzzle.part.parse().map_err(|_| "invalid part")?;
+    let mut candidates = extract::extract(&description, part);
+
+    if candidates.is_empty() {
+        println!("puzzle[{}]: no examples found", puzzle);
+        return Ok(());
+    }
+
+    if !force {
+        let existing: Vec<Example> = std::fs::read_to_string(&puzzle.examples_path)
+            .ok()
+            .and_then(|examples| serde_json::from_str(&examples).ok())
+            .unwrap_or_default();
+
+        if existing.iter().any(|example| !example.sample.is_empty()) {
+            eprintln!("puzzle file already exists: {}", puzzle.examples_path);
+            return Ok(());
+        }
+    }
+
+    if interactive {
+        let mut reviewed = vec![];
+
+        for (i, candidate) in candidates.into_iter().enumerate() {
+            reviewed.extend(review(puzzle, i + 1, candidate)?);
+        }
+
+        candidates = reviewed;
+    }
+
+    let examples = serde_json::to_string_pretty(&candidates)
+        .map_err(|err| format!("failed to serialize examples: {}", err))?;
+
+    std::fs::write(&puzzle.examples_path, examples)
+        .map_err(|err| format!("failed to write examples file: {}", err))?;
+
+    println!(
+        "puzzle[{}]: examples written={} path={}",
+        puzzle,
+        candidates.len(),
+        puzzle.examples_path
+    );
+
+    Ok(())
+}
+
+fn review(
+    puzzle: &Puzzle,
+    index: usize,
+    mut candidate: Example,
+) -> Result<Option<Example>, String> {
+    println!(
+        "puzzle[{}]: candidate[#{}] expected={}",
+        puzzle,
+        index,
+        candidate.expected.as_deref().unwrap_or("unknown")
+    );
+    println!("{}", candidate.sample);
+    print!("accept? [y/n/<expected>] ");
+
+    std::io::stdout()
+        .flush()
+        .map_err(|err| format!("failed to flush stdout: {}", err))?;
+
+    let mut reply = String::new();
+    std::io::stdin()
+        .read_line(&mut reply)
+        .map_err(|err| format!("failed to read reply: {}", err))?;
+
+    let reply = reply.trim();
+
+    match reply.to_lowercase().as_str() {
+        "y" | "yes" => {}
+        "n" | "no" | "" => return Ok(None),
+        _ => candidate.expected = Some(reply.to_string()),
+    }
+
+    candidate.pending = candidate.expected.is_none();
+
+    Ok(Some(candidate))
+}
+
+fn run_all(year: Option<u16>, day: Option<u8>, part: Option<u8>) {
+    let matches = |filter: Option<u16>, value: &str| match filter {
+        Some(filter) => value.parse::<u16>().ok() == Some(filter),
+        None => true,
+    };
+
+    println!(
+        "{:<12} {:<20} {:>10} {:>10}",
+        "puzzle", "answer", "parse", "solve"
+    );
+
+    let entries = registry::all()
+        .filter(|entry| {
+            let puzzle = entry.puzzle();
+
+            matches(year, &puzzle.year)
+                && matches(day.map(u16::from), &puzzle.day)
+                && matches(part.map(u16::from), &puzzle.part)
+        })
+        .collect::<Vec<_>>();
+
+    for (puzzle, run) in runs(&entries) {
+        let Some(run) = run else {
+            println!(
+                "{:<12} {:<20} {:>10} {:>10}",
+                puzzle.id, "missing input", "-", "-"
+            );
+            continue;
+        };
+
+        println!(
+            "{:<12} {:<20} {:>10} {:>10}",
+            puzzle.id,
+            match &run.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => err.to_string(),
+            },
+            run.parse_time,
+            run.solve_time
+        );
+    }
+}
+
+fn compare(puzzle: Puzzle, fetch: bool) -> usize {
+    let entry = find(&puzzle);
+    let mut failed = 0;
+
+    let profiles = std::iter::once(None).chain(
+        settings()
+            .profiles
+            .keys()
+            .map(|profile| Some(profile.as_str())),
+    );
+
+    println!(
+        "{:<12} {:<20} {:<20} {:>10} {:<10}",
+        "profile", "answer", "accepted", "solve", "status"
+    );
+
+    for profile in profiles {
+        let name = profile.unwrap_or("default");
+
+        let input = puzzle.with_profile(profile).and_then(|puzzle| {
+            let input = match cache::input(&puzzle) {
+                Ok(Some(input)) => Ok(input),
+                Ok(None) if fetch => provider::for_profile(profile)
+                    .and_then(|provider| provider.input(&puzzle))
+                    .and_then(|input| cache::save(&puzzle, &input).map(|_| input)),
+                Ok(None) => Err("missing input".to_string()),
+                Err(err) => Err(err),
+            };
+
+            input.map(|input| (puzzle, input))
+        });
+
+        let (puzzle, input) = match input {
+            Ok(input) => input,
+            Err(err) => {
+                println!(
+                    "{:<12} {:<20} {:<20} {:>10} {:<10}",
+                    name, "-", "-", "-", err
+                );
+                continue;
+            }
+        };
+
+        let accepted = match Answers::load(&puzzle) {
+            Ok(answers) => answers.accepted(&puzzle).cloned(),
+            Err(err) => {
+                println!(
+                    "{:<12} {:<20} {:<20} {:>10} {:<10}",
+                    name, "-", "-", "-", err
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        let run = (entry.run)(&input);
+
+        let (answer, status) = match (&run.answer, &accepted) {
+            (Ok(answer), Some(accepted)) if answer.matches(&accepted.to_string()) => {
+                (answer.to_string(), "ok")
+            }
+            (Ok(answer), Some(_)) => (answer.to_string(), "diverged"),
+            (Ok(answer), None) => (answer.to_string(), "unknown"),
+            (Err(err), _) => (err.to_string(), "failed"),
+        };
+
+        if status == "diverged" || status == "failed" {
+            failed += 1;
+        }
+
+        println!(
+            "{:<12} {:<20} {:<20} {:>10} {:<10}",
+            name,
+            answer,
+            accepted.map_or("-".to_string(), |accepted| accepted.to_string()),
+            run.solve_time,
+            status
+        );
+    }
+
+    failed
+}
+
+fn verify() {
+    let mut diverged = 0;
+    let mut broken = 0;
+
+    println!(
+        "{:<12} {:<20} {:<20} {:<10}",
+        "puzzle", "answer", "accepted", "status"
+    );
+
+    let accepted = registry::all()
+        .filter_map(|entry| {
+            let puzzle = entry.puzzle();
+
+            let accepted = match Answers::load(&puzzle) {
+                Ok(answers) => answers.accepted(&puzzle).cloned(),
+                Err(err) => {
+                    println!("{:<12} {:<20} {:<20} {:<10}", puzzle.id, "-", "-", err);
+                    broken += 1;
+                    return None;
+                }
+            };
+
+            if accepted.is_none() {
+                println!(
+                    "{:<12} {:<20} {:<20} {:<10}",
+                    puzzle.id, "-", "-", "unknown"
+                );
+            }
+
+            accepted.map(|accepted| (entry, accepted))
+        })
+        .collect::<Vec<_>>();
+
+    let entries = accepted.iter().map(|(entry, _)| *entry).collect::<Vec<_>>();
+
+    for ((puzzle, run), (_, accepted)) in runs(&entries).into_iter().zip(accepted.iter()) {
+        let Some(run) = run else {
+            println!(
+                "{:<12} {:<20} {:<20} {:<10}",
+                puzzle.id, "-", accepted, "missing input"
+            );
+            continue;
+        };
+
+        let (answer, status) = match run.answer {
+            Ok(answer) if answer.matches(&accepted.to_string()) => (answer.to_string(), "ok"),
+            Ok(answer) => (answer.to_string(), "diverged"),
+            Err(err) => (err.to_string(), "diverged"),
+        };
+
+        if status == "diverged" {
+            diverged += 1;
+        }
+
+        println!(
+            "{:<12} {:<20} {:<20} {:<10}",
+            puzzle.id, answer, accepted, status
+        );
+    }
+
+    if broken > 0 {
+        eprintln!("{} puzzle(s) have unreadable answers", broken);
+    }
+
+    if diverged > 0 {
+        eprintln!("{} puzzle(s) diverged from their accepted answer", diverged);
+    }
+
+    if diverged + broken > 0 {
+        std::process::exit(1);
+    }
+}
+
+// runs the puzzles on their input, none when it is missing; the parts of a day-level module
+// that are both selected run on a single parse
+fn runs(entries: &[&'static registry::Entry]) -> Vec<(Puzzle, Option<Run>)> {
+    let mut runs = Vec::new();
+    let mut rest = entries;
+
+    while let Some(entry) = rest.first() {
+        let puzzle = entry.puzzle();
+        let input = std::fs::read_to_string(&puzzle.input_path).ok();
+
+        let parts = rest
+            .iter()
+            .take_while(|other| other.day.is_some() && other.id[..7] == entry.id[..7])
+            .count();
+
+        let (selected, remaining) = rest.split_at(parts.max(1));
+
+        match (input, entry.day) {
+            (Some(input), Some(day)) if parts > 1 => {
+                let puzzles = selected.iter().map(|entry| entry.puzzle());
+
+                runs.extend(puzzles.zip(day(&input).into_iter().map(Some)));
+            }
+            (input, _) => runs.extend(
+                selected
+                    .iter()
+                    .map(|entry| (entry.puzzle(), input.as_deref().map(entry.run))),
+            ),
+        }
+
+        rest = remaining;
+    }
+
+    runs
+}
+
+fn find(puzzle: &Puzzle) -> &'static registry::Entry {
+    match registry::find(&puzzle.id) {
+        Some(entry) => entry,
+        None => {
+            eprintln!("puzzle is not registered: {}", puzzle.id);
+            std::process::exit(1);
+        }
+    }
 }
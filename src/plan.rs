@@ -0,0 +1,303 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+pub struct Operation {
+    path: PathBuf,
+    kind: Kind,
+}
+
+enum Kind {
+    Write { content: String, replace: bool },
+    Move { from: PathBuf },
+}
+
+// a set of file writes and moves, validated up front and applied all at once
+#[derive(Default)]
+pub struct Plan {
+    operations: Vec<Operation>,
+}
+
+enum Undo {
+    Remove(PathBuf),
+    RemoveDir(PathBuf),
+    Restore(PathBuf, Vec<u8>),
+    Move(PathBuf, PathBuf),
+}
+
+enum State {
+    Create,
+    Move,
+    Missing,
+    Overwrite,
+    Replace,
+    Unchanged,
+}
+
+impl Operation {
+    fn state(&self) -> State {
+        let (content, replace) = match &self.kind {
+            Kind::Write { content, replace } => (content, *replace),
+            Kind::Move { .. } if self.path.exists() => return State::Overwrite,
+            Kind::Move { from } if !from.exists() => return State::Missing,
+            Kind::Move { .. } => return State::Move,
+        };
+
+        match std::fs::read(&self.path) {
+            Ok(existing) if existing == content.as_bytes() => State::Unchanged,
+            Ok(_) if replace => State::Replace,
+            Ok(_) => State::Overwrite,
+            Err(_) => State::Create,
+        }
+    }
+}
+
+impl Plan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // writes a file, conflicting with an existing one unless forced
+    pub fn write(&mut self, path: impl Into<PathBuf>, content: impl Into<String>) {
+        self.push(
+            path.into(),
+            Kind::Write {
+                content: content.into(),
+                replace: false,
+            },
+        );
+    }
+
+    // writes a file that is expected to change, i.e a downloaded description
+    pub fn replace(&mut self, path: impl Into<PathBuf>, content: impl Into<String>) {
+        self.push(
+            path.into(),
+            Kind::Write {
+                content: content.into(),
+                replace: true,
+            },
+        );
+    }
+
+    // moves a file or directory, conflicting with an existing destination
+    pub fn rename(&mut self, from: impl Into<PathBuf>, to: impl Into<PathBuf>) {
+        self.push(to.into(), Kind::Move { from: from.into() });
+    }
+
+    // adds the operations of another plan, i.e one staged for a single puzzle
+    pub fn extend(&mut self, other: Plan) {
+        for operation in other.operations {
+            self.push(operation.path, operation.kind);
+        }
+    }
+
+    fn push(&mut self, path: PathBuf, kind: Kind) {
+        self.operations.retain(|operation| operation.path != path);
+        self.operations.push(Operation { path, kind });
+    }
+
+    pub fn validate(&self, force: bool) -> Result<(), String> {
+        if force {
+            return Ok(());
+        }
+
+        let conflicts = self
+            .operations
+            .iter()
+            .filter(|operation| matches!(operation.state(), State::Overwrite))
+            .map(|operation| operation.path.display().to_string())
+            .collect::<Vec<_>>();
+
+        let missing = self
+            .operations
+            .iter()
+            .filter(|operation| matches!(operation.state(), State::Missing))
+            .map(|operation| operation.path.display().to_string())
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(format!("nothing to move to: {}", missing.join(", ")));
+        }
+
+        match conflicts.is_empty() {
+            true => Ok(()),
+            false => Err(format!(
+                "puzzle files already exist: {}",
+                conflicts.join(", ")
+            )),
+        }
+    }
+
+    pub fn apply(&self) -> Result<(), String> {
+        let mut undo = vec![];
+
+        for operation in self.operations.iter() {
+            if let Err(err) = Plan::perform(operation, &mut undo) {
+                Plan::rollback(undo);
+                return Err(format!(
+                    "unable to write {}, rolled back: {}",
+                    operation.path.display(),
+                    err
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn perform(operation: &Operation, undo: &mut Vec<Undo>) -> std::io::Result<()> {
+        if let State::Unchanged = operation.state() {
+            return Ok(());
+        }
+
+        if let Some(parent) = operation.path.parent() {
+            let missing = parent
+                .ancestors()
+                .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+                .map(Path::to_path_buf)
+                .collect::<Vec<_>>();
+
+            std::fs::create_dir_all(parent)?;
+
+            undo.extend(missing.into_iter().rev().map(Undo::RemoveDir));
+        }
+
+        let content = match &operation.kind {
+            Kind::Write { content, .. } => content,
+            Kind::Move { from } => {
+                std::fs::rename(from, &operation.path)?;
+                undo.push(Undo::Move(operation.path.clone(), from.clone()));
+                return Ok(());
+            }
+        };
+
+        match std::fs::read(&operation.path) {
+            Ok(existing) => undo.push(Undo::Restore(operation.path.clone(), existing)),
+            Err(_) => undo.push(Undo::Remove(operation.path.clone())),
+        }
+
+        // write next to the target and rename, so a file is never left half written
+        let temporary = operation.path.with_extension("scaffold.tmp");
+
+        std::fs::write(&temporary, content)?;
+        std::fs::rename(&temporary, &operation.path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&temporary);
+        })
+    }
+
+    fn rollback(undo: Vec<Undo>) {
+        for step in undo.into_iter().rev() {
+            let _ = match step {
+                Undo::Remove(path) => std::fs::remove_file(path),
+                Undo::RemoveDir(path) => std::fs::remove_dir(path),
+                Undo::Restore(path, content) => std::fs::write(path, content),
+                Undo::Move(from, to) => std::fs::rename(from, to),
+            };
+        }
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for operation in self.operations.iter() {
+            let state = match operation.state() {
+                State::Create => "create",
+                State::Move | State::Missing => "move",
+                State::Overwrite => "overwrite",
+                State::Replace => "update",
+                State::Unchanged => "unchanged",
+            };
+
+            match &operation.kind {
+                Kind::Move { from } => writeln!(
+                    f,
+                    "{:<10} {} -> {}",
+                    state,
+                    from.display(),
+                    operation.path.display()
+                )?,
+                Kind::Write { .. } => writeln!(f, "{:<10} {}", state, operation.path.display())?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn existing_files_conflict_unless_unchanged_or_replaced() {
+        let dir = tempfile::tempdir().expect("failed to create dir");
+        let path = dir.path().join("solution.rs");
+
+        std::fs::write(&path, "// solved").expect("failed to write file");
+
+        let mut plan = Plan::new();
+
+        plan.write(&path, "// solved");
+        assert_eq!(plan.validate(false), Ok(()));
+
+        plan.write(&path, "// template");
+        assert!(plan.validate(false).is_err());
+        assert_eq!(plan.validate(true), Ok(()));
+
+        // a later operation on the same path takes the place of the earlier one
+        plan.replace(&path, "// description");
+        assert_eq!(plan.validate(false), Ok(()));
+        assert_eq!(plan.to_string(), format!("update     {}\n", path.display()));
+
+        let mut moves = Plan::new();
+
+        moves.rename(dir.path().join("missing"), dir.path().join("moved"));
+        assert!(moves.validate(false).is_err());
+    }
+
+    #[test]
+    fn failed_plans_are_rolled_back() {
+        let dir = tempfile::tempdir().expect("failed to create dir");
+        let existing = dir.path().join("mod.rs");
+
+        std::fs::write(&existing, "before").expect("failed to write file");
+        std::fs::write(dir.path().join("bin"), "").expect("failed to write file");
+
+        let mut plan = Plan::new();
+
+        plan.replace(&existing, "after");
+        plan.write(dir.path().join("y2015/d01/solution.rs"), "// template");
+        // the bin dir is a file, the last write fails
+        plan.write(dir.path().join("bin/2015_01_01.rs"), "// bin");
+
+        let err = plan.apply().expect_err("plan should fail");
+
+        assert!(err.contains("rolled back"), "{}", err);
+        assert_eq!(
+            std::fs::read_to_string(&existing).expect("failed to read file"),
+            "before"
+        );
+        assert!(!dir.path().join("y2015").exists());
+    }
+
+    #[test]
+    fn plans_apply_writes_and_moves() {
+        let dir = tempfile::tempdir().expect("failed to create dir");
+
+        std::fs::create_dir_all(dir.path().join("_2015_06")).expect("failed to create dir");
+
+        let mut plan = Plan::new();
+        let mut staged = Plan::new();
+
+        staged.write(dir.path().join("mod.rs"), "crate::puzzles! {}");
+        plan.rename(dir.path().join("_2015_06"), dir.path().join("y2015/d06"));
+        plan.extend(staged);
+
+        assert_eq!(plan.apply(), Ok(()));
+        assert!(dir.path().join("y2015/d06").is_dir());
+        assert!(!dir.path().join("_2015_06").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("mod.rs")).expect("failed to read file"),
+            "crate::puzzles! {}"
+        );
+    }
+}
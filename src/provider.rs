@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use aoc_client::{AocClient, SubmissionOutcome};
+
+use crate::client::{self, Hint, Submission};
+use crate::config::{self, Session};
+use crate::template::Puzzle;
+
+// the tests serve puzzles from a directory, it is a hook rather than a setting
+const FIXTURES_ENV_VAR: &str = "AOC_FIXTURES";
+
+pub trait Provider {
+    fn input(&self, puzzle: &Puzzle) -> Result<String, String>;
+
+    fn description(&self, puzzle: &Puzzle) -> Result<String, String>;
+
+    fn submit(&self, puzzle: &Puzzle, answer: &str) -> Result<Submission, String>;
+}
+
+pub fn current() -> Result<Box<dyn Provider>, String> {
+    for_profile(config::get()?.profile.as_deref())
+}
+
+pub fn for_profile(profile: Option<&str>) -> Result<Box<dyn Provider>, String> {
+    let config = config::get()?;
+
+    Ok(
+        match std::env::var_os(FIXTURES_ENV_VAR).filter(|root| !root.is_empty()) {
+            Some(root) => Box::new(Fixtures::new(root, profile)),
+            None => Box::new(Aoc::new(config.session(profile).clone())),
+        },
+    )
+}
+
+pub struct Aoc {
+    session: Session,
+}
+
+impl Aoc {
+    pub fn new(session: Session) -> Self {
+        Self { session }
+    }
+
+    fn client(&self, puzzle: &Puzzle) -> Result<AocClient, String> {
+        AocClient::builder()
+            .session_cookie(client::session_cookie(&self.session)?)
+            .map_err(|err| err.to_string())?
+            .year(puzzle.year.parse().map_err(|_| "invalid year")?)
+            .map_err(|err| err.to_string())?
+            .day(puzzle.day.parse().map_err(|_| "invalid day")?)
+            .map_err(|err| err.to_string())?
+            .build()
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl Provider for Aoc {
+    fn input(&self, puzzle: &Puzzle) -> Result<String, String> {
+        self.client(puzzle)?
+            .get_input()
+            .map_err(|err| err.to_string())
+    }
+
+    fn description(&self, puzzle: &Puzzle) -> Result<String, String> {
+        let html = self
+            .client(puzzle)?
+            .get_puzzle_html()
+            .map_err(|err| err.to_string())?;
+
+        Ok(html2md::parse_html(&html))
+    }
+
+    fn submit(&self, puzzle: &Puzzle, answer: &str) -> Result<Submission, String> {
+        client::submit(puzzle, answer, &self.session)
+    }
+}
+
+// serves puzzles from a directory, laid out as:
+// inputs/YYYY_DD.txt, descriptions/YYYY_DD.md and answers.json ({ "YYYY_DD_PP": "answer" })
+// profiles keep their inputs and answers in inputs/<profile>/ and answers/<profile>.json
+pub struct Fixtures {
+    root: PathBuf,
+    profile: Option<String>,
+}
+
+impl Fixtures {
+    pub fn new(root: impl Into<PathBuf>, profile: Option<&str>) -> Self {
+        Self {
+            root: root.into(),
+            profile: profile.map(str::to_string),
+        }
+    }
+
+    fn read(&self, path: PathBuf) -> Result<String, String> {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))
+    }
+}
+
+impl Provider for Fixtures {
+    fn input(&self, puzzle: &Puzzle) -> Result<String, String> {
+        let dir = match &self.profile {
+            Some(profile) => self.root.join("inputs").join(profile),
+            None => self.root.join("inputs"),
+        };
+
+        self.read(dir.join(format!("{}_{}.txt", puzzle.year, puzzle.day)))
+    }
+
+    fn description(&self, puzzle: &Puzzle) -> Result<String, String> {
+        self.read(
+            self.root
+                .join("descriptions")
+                .join(format!("{}_{}.md", puzzle.year, puzzle.day)),
+        )
+    }
+
+    fn submit(&self, puzzle: &Puzzle, answer: &str) -> Result<Submission, String> {
+        let path = match &self.profile {
+            Some(profile) => self.root.join("answers").join(format!("{}.json", profile)),
+            None => self.root.join("answers.json"),
+        };
+
+        let answers: BTreeMap<String, String> = serde_json::from_str(&self.read(path)?)
+            .map_err(|err| format!("unable to parse fixture answers: {}", err))?;
+
+        let expected = answers
+            .get(&puzzle.id)
+            .ok_or_else(|| format!("no fixture answer for {}", puzzle.id))?;
+
+        if expected.trim() == answer.trim() {
+            return Ok(Submission {
+                outcome: SubmissionOutcome::Correct,
+                hint: None,
+                wait: None,
+            });
+        }
+
+        let hint = match (answer.trim().parse::<i64>(), expected.trim().parse::<i64>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => Some(Hint::TooHigh),
+            (Ok(answer), Ok(expected)) if answer < expected => Some(Hint::TooLow),
+            _ => None,
+        };
+
+        Ok(Submission {
+            outcome: SubmissionOutcome::Incorrect,
+            hint,
+            wait: None,
+        })
+    }
+}
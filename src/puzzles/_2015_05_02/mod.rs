@@ -1 +0,0 @@
-pub mod solution;
\ No newline at end of file
@@ -1,21 +1,14 @@
-pub mod _2015_01_01;
-pub mod _2015_01_02;
-pub mod _2015_02_01;
-pub mod _2015_02_02;
-pub mod _2015_03_01;
-pub mod _2015_03_02;
-pub mod _2015_04_01;
-pub mod _2015_04_02;
-pub mod _2015_05_01;
-pub mod _2015_05_02;
-pub mod _2015_06_01;
-pub mod _2015_06_02;
-pub mod _2015_07_01;
-pub mod _2015_07_02;
-pub mod _2015_08_01;
-pub mod _2015_08_02;
-pub mod _2015_09_01;
-pub mod _2015_09_02;
-
-pub mod _2015_10_01;
-pub mod _2015_10_02;
\ No newline at end of file
+crate::puzzles! {
+    y2015 {
+        d01 { part1, part2 },
+        d02 { part1, part2 },
+        d03 { part1, part2 },
+        d04 { part1, part2 },
+        d05 { part1, part2 },
+        d06,
+        d07 { part1, part2 },
+        d08 { part1, part2 },
+        d09,
+        d10 { part1, part2 },
+    },
+}
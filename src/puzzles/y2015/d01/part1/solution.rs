@@ -29,17 +29,17 @@ impl From<Direction> for i32 {
 impl Solution for Puzzle {
     type Input = Vec<Direction>;
 
-    fn parse(input: &str) -> Self::Input {
-        input.chars().map(Direction::from).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.chars().map(Direction::from).collect())
     }
 
-    fn solve(directions: Self::Input) -> Option<String> {
+    fn solve(directions: Self::Input) -> Result<Answer, SolveError> {
         let mut floor = 0;
 
         for direction in directions {
             floor += i32::from(direction);
         }
 
-        Some(floor.to_string())
+        Ok(floor.into())
     }
 }
@@ -29,24 +29,21 @@ impl From<Direction> for i32 {
 impl Solution for Puzzle {
     type Input = Vec<Direction>;
 
-    fn parse(input: &str) -> Self::Input {
-        input.chars().map(Direction::from).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.chars().map(Direction::from).collect())
     }
 
-    fn solve(directions: Self::Input) -> Option<String> {
+    fn solve(directions: Self::Input) -> Result<Answer, SolveError> {
         let mut floor = 0;
-        let mut position = 1;
 
-        for direction in directions {
+        for (position, direction) in (1..).zip(directions) {
             floor += i32::from(direction);
 
             if floor < 0 {
-                return Some(position.to_string());
+                return Ok(position.into());
             }
-
-            position += 1;
         }
 
-        Some(floor.to_string())
+        Err(SolveError::new("santa never enters the basement"))
     }
 }
@@ -0,0 +1,58 @@
+crate::puzzle!("2015_02_01");
+
+pub struct Gift {
+    l: u32,
+    w: u32,
+    h: u32,
+}
+
+impl Gift {
+    fn area(&self) -> u32 {
+        2 * self.l * self.w + 2 * self.w * self.h + 2 * self.h * self.l
+    }
+
+    fn slack(&self) -> u32 {
+        let mut sides = [self.l, self.w, self.h];
+        sides.sort_unstable();
+
+        sides[0] * sides[1]
+    }
+}
+
+impl Solution for Puzzle {
+    type Input = Vec<Gift>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let mut parts = line.split('x');
+
+                let mut dimension = |name: &str| {
+                    let part = parts.next().ok_or_else(|| {
+                        ParseError::new(i + 1, line.len() + 1, format!("missing {}", name))
+                    })?;
+
+                    part.parse().map_err(|_| {
+                        ParseError::at(i + 1, line, part, format!("invalid {}: {}", name, part))
+                    })
+                };
+
+                Ok(Gift {
+                    l: dimension("length")?,
+                    w: dimension("width")?,
+                    h: dimension("height")?,
+                })
+            })
+            .collect()
+    }
+
+    fn solve(gifts: Self::Input) -> Result<Answer, SolveError> {
+        Ok(gifts
+            .iter()
+            .map(|gift| gift.area() + gift.slack())
+            .sum::<u32>()
+            .into())
+    }
+}
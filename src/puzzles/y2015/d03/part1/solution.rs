@@ -0,0 +1,53 @@
+crate::puzzle!("2015_03_01");
+
+use std::ops::AddAssign;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector(i32, i32);
+
+impl TryFrom<char> for Vector {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Vector(0, 1)),
+            'v' => Ok(Vector(0, -1)),
+            '>' => Ok(Vector(1, 0)),
+            '<' => Ok(Vector(-1, 0)),
+            _ => Err(format!("invalid direction char: {}", c)),
+        }
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+        self.1 += other.1;
+    }
+}
+
+impl Solution for Puzzle {
+    type Input = Vec<Vector>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Vector::try_from(c).map_err(|err| ParseError::new(1, i + 1, err)))
+            .collect()
+    }
+
+    fn solve(vectors: Self::Input) -> Result<Answer, SolveError> {
+        let mut visited = std::collections::HashSet::new();
+
+        let mut santa_position = Vector(0, 0);
+        visited.insert(santa_position);
+
+        for vector in vectors {
+            santa_position += vector;
+            visited.insert(santa_position);
+        }
+
+        Ok(visited.len().into())
+    }
+}
@@ -5,14 +5,16 @@ use std::ops::AddAssign;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vector(i32, i32);
 
-impl From<char> for Vector {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Vector {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '^' => Vector(0, 1),
-            'v' => Vector(0, -1),
-            '>' => Vector(1, 0),
-            '<' => Vector(-1, 0),
-            _ => panic!("invalid direction char: {}", c),
+            '^' => Ok(Vector(0, 1)),
+            'v' => Ok(Vector(0, -1)),
+            '>' => Ok(Vector(1, 0)),
+            '<' => Ok(Vector(-1, 0)),
+            _ => Err(format!("invalid direction char: {}", c)),
         }
     }
 }
@@ -27,11 +29,15 @@ impl AddAssign for Vector {
 impl Solution for Puzzle {
     type Input = Vec<Vector>;
 
-    fn parse(input: &str) -> Self::Input {
-        input.chars().map(Vector::from).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Vector::try_from(c).map_err(|err| ParseError::new(1, i + 1, err)))
+            .collect()
     }
 
-    fn solve(vectors: Self::Input) -> Option<String> {
+    fn solve(vectors: Self::Input) -> Result<Answer, SolveError> {
         let mut visited = std::collections::HashSet::new();
 
         let mut santa_position = Vector(0, 0);
@@ -53,6 +59,6 @@ impl Solution for Puzzle {
             }
         }
 
-        Some(visited.len().to_string())
+        Ok(visited.len().into())
     }
 }
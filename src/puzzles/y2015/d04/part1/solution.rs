@@ -0,0 +1,28 @@
+crate::puzzle!("2015_04_01");
+
+use rayon::prelude::*;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn solve(secret: Self::Input) -> Result<Answer, SolveError> {
+        let max = u32::try_from(secret.len())
+            .ok()
+            .and_then(|digits| 10u32.checked_pow(digits))
+            .ok_or_else(|| SolveError::new("secret is too long to search its suffixes"))?;
+        let range = 0..max;
+
+        let result = range
+            .into_par_iter()
+            .find_first(|&i| {
+                format!("{:x}", md5::compute(format!("{}{}", secret, i))).starts_with("00000")
+            })
+            .ok_or_else(|| SolveError::new("no suffix produces a matching hash"))?;
+
+        Ok(result.into())
+    }
+}
@@ -60,11 +60,11 @@ impl Rule for ForbiddenRule {
 impl Solution for Puzzle {
     type Input = Vec<String>;
 
-    fn parse(input: &str) -> Self::Input {
-        input.lines().map(str::to_string).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
     }
 
-    fn solve(inputs: Self::Input) -> Option<String> {
+    fn solve(inputs: Self::Input) -> Result<Answer, SolveError> {
         let quantifier = StringQuantifier::new(vec![
             Box::new(VowelRule),
             Box::new(DoubleRule),
@@ -76,6 +76,6 @@ impl Solution for Puzzle {
             .filter(|input| quantifier.kind(input) == StringKind::Nice)
             .count();
 
-        Some(nice.to_string())
+        Ok(nice.into())
     }
 }
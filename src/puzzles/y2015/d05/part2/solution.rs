@@ -59,11 +59,11 @@ impl Rule for RepeatRule {
 impl Solution for Puzzle {
     type Input = Vec<String>;
 
-    fn parse(input: &str) -> Self::Input {
-        input.lines().map(str::to_string).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
     }
 
-    fn solve(inputs: Self::Input) -> Option<String> {
+    fn solve(inputs: Self::Input) -> Result<Answer, SolveError> {
         let quantifier = StringQuantifier::new(vec![Box::new(PairsRule), Box::new(RepeatRule)]);
 
         let nice = inputs
@@ -71,6 +71,6 @@ impl Solution for Puzzle {
             .filter(|input| quantifier.kind(input) == StringKind::Nice)
             .count();
 
-        Some(nice.to_string())
+        Ok(nice.into())
     }
 }
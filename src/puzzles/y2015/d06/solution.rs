@@ -0,0 +1,171 @@
+crate::day!("2015_06");
+
+#[derive(Debug, Clone, Copy)]
+struct Light {
+    on: bool,
+    brightness: u32,
+}
+
+struct Grid {
+    lights: Vec<Vec<Light>>,
+}
+
+impl Grid {
+    fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            lights: vec![
+                vec![
+                    Light {
+                        on: false,
+                        brightness: 0
+                    };
+                    cols
+                ];
+                rows
+            ],
+        }
+    }
+
+    fn apply(&mut self, instruction: &Instruction, range: &Range) -> Result<(), SolveError> {
+        let Range {
+            from: (x1, y1),
+            to: (x2, y2),
+        } = *range;
+
+        if x2 >= self.lights.len() || y2 >= self.lights[0].len() {
+            return Err(SolveError::new(format!(
+                "range {},{} through {},{} is outside the grid",
+                x1, y1, x2, y2
+            )));
+        }
+
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                match instruction {
+                    Instruction::TurnOn => {
+                        self.lights[x][y].on = true;
+                        self.lights[x][y].brightness += 1;
+                    }
+                    Instruction::TurnOff => {
+                        self.lights[x][y].on = false;
+                        self.lights[x][y].brightness =
+                            self.lights[x][y].brightness.saturating_sub(1);
+                    }
+                    Instruction::Toggle => {
+                        self.lights[x][y].on = !self.lights[x][y].on;
+                        self.lights[x][y].brightness += 2;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Range {
+    from: (usize, usize),
+    to: (usize, usize),
+}
+
+pub enum Instruction {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+fn lights(instructions: &<Puzzle as Day>::Input, params: &Params) -> Result<Grid, SolveError> {
+    let size = params.get_or("size", 1000)?;
+
+    let mut grid = Grid::new(size, size);
+
+    for (instruction, range) in instructions {
+        grid.apply(instruction, range)?;
+    }
+
+    Ok(grid)
+}
+
+impl Day for Puzzle {
+    type Input = Vec<(Instruction, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let mut words = line.split_whitespace();
+
+                let unexpected = |word: Option<&str>| match word {
+                    Some(word) => {
+                        ParseError::at(i + 1, line, word, format!("unexpected word: {}", word))
+                    }
+                    None => ParseError::new(i + 1, line.len() + 1, "unexpected end of line"),
+                };
+
+                let instruction = match words.next() {
+                    Some("turn") => match words.next() {
+                        Some("on") => Instruction::TurnOn,
+                        Some("off") => Instruction::TurnOff,
+                        word => return Err(unexpected(word)),
+                    },
+                    Some("toggle") => Instruction::Toggle,
+                    word => return Err(unexpected(word)),
+                };
+
+                let coordinate = |word: Option<&str>| {
+                    let word = word.ok_or_else(|| unexpected(None))?;
+                    let invalid = || {
+                        ParseError::at(i + 1, line, word, format!("invalid coordinate: {}", word))
+                    };
+
+                    let (x, y) = word.split_once(',').ok_or_else(invalid)?;
+
+                    match (x.parse(), y.parse()) {
+                        (Ok(x), Ok(y)) => Ok((x, y)),
+                        _ => Err(invalid()),
+                    }
+                };
+
+                let from = coordinate(words.next())?;
+
+                match words.next() {
+                    Some("through") => {}
+                    word => return Err(unexpected(word)),
+                }
+
+                let to = coordinate(words.next())?;
+
+                Ok((instruction, Range { from, to }))
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Self::part1_with(instructions, &Params::default())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Self::part2_with(instructions, &Params::default())
+    }
+
+    fn part1_with(instructions: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(lights(instructions, params)?
+            .lights
+            .iter()
+            .flatten()
+            .filter(|&&light| light.on)
+            .count()
+            .into())
+    }
+
+    fn part2_with(instructions: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(lights(instructions, params)?
+            .lights
+            .iter()
+            .flatten()
+            .map(|&light| light.brightness)
+            .sum::<u32>()
+            .into())
+    }
+}
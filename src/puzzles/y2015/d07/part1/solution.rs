@@ -17,10 +17,12 @@ impl Memory {
         self.vars.get(key)
     }
 
-    pub fn apply(&mut self, instructions: &[Instruction]) {
+    pub fn apply(&mut self, instructions: &[Instruction]) -> Result<(), SolveError> {
         let mut applied = HashSet::new();
 
         while applied.len() < instructions.len() {
+            let before = applied.len();
+
             for instruction in instructions.iter() {
                 if applied.contains(instruction) {
                     continue;
@@ -30,13 +32,22 @@ impl Memory {
                     applied.insert(instruction);
                 }
             }
+
+            if applied.len() == before {
+                return Err(SolveError::new(format!(
+                    "{} instruction(s) depend on wires that never get a signal",
+                    instructions.len() - applied.len()
+                )));
+            }
         }
+
+        Ok(())
     }
 
     fn apply_single(&mut self, instruction: &Instruction) -> bool {
         match instruction {
             Instruction::Set { value, target } => {
-                let value = match self.parse_or_get(&value) {
+                let value = match self.parse_or_get(value) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -44,7 +55,7 @@ impl Memory {
                 self.vars.insert(target.clone(), value);
             }
             Instruction::Not { value, target } => {
-                let value = match self.parse_or_get(&value) {
+                let value = match self.parse_or_get(value) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -52,12 +63,12 @@ impl Memory {
                 self.vars.insert(target.clone(), !value);
             }
             Instruction::And { a, b, target } => {
-                let a = match self.parse_or_get(&a) {
+                let a = match self.parse_or_get(a) {
                     Some(value) => value,
                     None => return false,
                 };
 
-                let b = match self.parse_or_get(&b) {
+                let b = match self.parse_or_get(b) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -65,12 +76,12 @@ impl Memory {
                 self.vars.insert(target.clone(), a & b);
             }
             Instruction::Or { a, b, target } => {
-                let a = match self.parse_or_get(&a) {
+                let a = match self.parse_or_get(a) {
                     Some(value) => value,
                     None => return false,
                 };
 
-                let b = match self.parse_or_get(&b) {
+                let b = match self.parse_or_get(b) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -78,12 +89,12 @@ impl Memory {
                 self.vars.insert(target.clone(), a | b);
             }
             Instruction::LShift { a, b, target } => {
-                let a = match self.parse_or_get(&a) {
+                let a = match self.parse_or_get(a) {
                     Some(value) => value,
                     None => return false,
                 };
 
-                let b = match self.parse_or_get(&b) {
+                let b = match self.parse_or_get(b) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -91,12 +102,12 @@ impl Memory {
                 self.vars.insert(target.clone(), a << b);
             }
             Instruction::RShift { a, b, target } => {
-                let a = match self.parse_or_get(&a) {
+                let a = match self.parse_or_get(a) {
                     Some(value) => value,
                     None => return false,
                 };
 
-                let b = match self.parse_or_get(&b) {
+                let b = match self.parse_or_get(b) {
                     Some(value) => value,
                     None => return false,
                 };
@@ -148,54 +159,62 @@ pub enum Instruction {
 impl Solution for Puzzle {
     type Input = Vec<Instruction>;
 
-    fn parse(input: &str) -> Self::Input {
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
         input
             .lines()
-            .map(|line| {
-                let parts = line.split(" -> ").collect::<Vec<_>>();
-                let lhs = parts[0].split_whitespace().collect::<Vec<_>>();
-                let rhs = parts[1];
+            .enumerate()
+            .map(|(i, line)| {
+                let (lhs, rhs) = line
+                    .split_once(" -> ")
+                    .ok_or_else(|| ParseError::new(i + 1, 1, "missing `->`"))?;
+                let lhs = lhs.split_whitespace().collect::<Vec<_>>();
 
                 match lhs.len() {
                     1 => {
                         let value = lhs[0].to_string();
                         let target = rhs.to_string();
 
-                        Instruction::Set { value, target }
+                        Ok(Instruction::Set { value, target })
                     }
                     2 => {
                         let value = lhs[1].to_string();
                         let target = rhs.to_string();
 
-                        Instruction::Not { value, target }
+                        Ok(Instruction::Not { value, target })
                     }
-                    _ => {
+                    3 => {
                         let op = lhs[1];
                         let a = lhs[0].to_string();
                         let b = lhs[2].to_string();
                         let target = rhs.to_string();
 
                         match op {
-                            "AND" => Instruction::And { a, b, target },
-                            "OR" => Instruction::Or { a, b, target },
-                            "LSHIFT" => Instruction::LShift { a, b, target },
-                            "RSHIFT" => Instruction::RShift { a, b, target },
-                            _ => unreachable!(),
+                            "AND" => Ok(Instruction::And { a, b, target }),
+                            "OR" => Ok(Instruction::Or { a, b, target }),
+                            "LSHIFT" => Ok(Instruction::LShift { a, b, target }),
+                            "RSHIFT" => Ok(Instruction::RShift { a, b, target }),
+                            _ => Err(ParseError::at(
+                                i + 1,
+                                line,
+                                op,
+                                format!("unknown operator: {}", op),
+                            )),
                         }
                     }
+                    _ => Err(ParseError::new(i + 1, 1, "expected one to three operands")),
                 }
             })
             .collect()
     }
 
-    fn solve(instructions: Self::Input) -> Option<String> {
+    fn solve(instructions: Self::Input) -> Result<Answer, SolveError> {
         let mut memory = Memory::new();
 
-        memory.apply(&instructions);
+        memory.apply(&instructions)?;
 
-        match memory.get("a") {
-            Some(value) => Some(value.to_string()),
-            None => None,
-        }
+        memory
+            .get("a")
+            .map(|value| Answer::from(*value))
+            .ok_or_else(|| SolveError::new("wire a has no signal"))
     }
 }
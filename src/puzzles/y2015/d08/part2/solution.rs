@@ -0,0 +1,117 @@
+use std::{iter::Peekable, str::Chars};
+
+crate::puzzle!("2015_08_02");
+
+#[derive(Debug)]
+pub enum Token {
+    Quote,
+    Character(char),
+    Escape(char),
+    Hex(char),
+}
+
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 0,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        match c {
+            '\n' => {
+                self.line += 1;
+                self.column = 0;
+            }
+            _ => self.column += 1,
+        }
+
+        Some(c)
+    }
+
+    fn next(&mut self) -> Option<Result<Token, ParseError>> {
+        let c = self.bump()?;
+
+        if c == ' ' {
+            return self.next();
+        }
+
+        let (line, column) = (self.line, self.column);
+        let peeked = self.chars.peek().copied();
+
+        if c == '\\' && peeked == Some('x') {
+            self.bump();
+
+            let hex: String = [self.bump(), self.bump()].into_iter().flatten().collect();
+
+            let token = match hex.len() {
+                2 => u8::from_str_radix(&hex, 16).ok(),
+                _ => None,
+            };
+
+            Some(token.map(|byte| Token::Hex(byte as char)).ok_or_else(|| {
+                ParseError::new(line, column, format!("invalid hex escape: \\x{}", hex))
+            }))
+        } else if c == '\\' && (peeked == Some('\\') || peeked == Some('"')) {
+            Some(Ok(Token::Escape(self.bump()?)))
+        } else if c == '"' {
+            Some(Ok(Token::Quote))
+        } else {
+            Some(Ok(Token::Character(c)))
+        }
+    }
+}
+
+impl Solution for Puzzle {
+    type Input = Vec<Token>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokenizer = Tokenizer::new(input);
+
+        let mut tokens = Vec::new();
+
+        while let Some(token) = tokenizer.next() {
+            tokens.push(token?);
+        }
+
+        Ok(tokens)
+    }
+
+    fn solve(tokens: Self::Input) -> Result<Answer, SolveError> {
+        let mut encoded = 0;
+        let mut code = 0;
+
+        for token in &tokens {
+            match token {
+                Token::Character(_) => {
+                    encoded += 1;
+                    code += 1;
+                }
+                Token::Quote => {
+                    encoded += 3;
+                    code += 1;
+                }
+                Token::Escape(_) => {
+                    encoded += 4;
+                    code += 2;
+                }
+                Token::Hex(_) => {
+                    encoded += 5;
+                    code += 4;
+                }
+            }
+        }
+
+        Ok((encoded - code).into())
+    }
+}
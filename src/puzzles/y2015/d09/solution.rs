@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+crate::day!("2015_09");
+
+// AI used to solve this puzzle.
+
+fn permutations(cities: &[String]) -> Vec<Vec<String>> {
+    if cities.len() <= 1 {
+        return vec![cities.to_vec()];
+    }
+
+    let mut result = Vec::new();
+
+    for i in 0..cities.len() {
+        let mut remaining = cities.to_vec();
+        let current = remaining.remove(i);
+
+        for mut perm in permutations(&remaining) {
+            perm.insert(0, current.clone());
+            result.push(perm);
+        }
+    }
+
+    result
+}
+
+fn routes((cities, distances): &<Puzzle as Day>::Input) -> Result<Vec<usize>, SolveError> {
+    permutations(cities)
+        .iter()
+        .map(|route| {
+            route
+                .windows(2)
+                .map(|pair| distances.get(&(pair[0].clone(), pair[1].clone())))
+                .sum::<Option<usize>>()
+        })
+        .collect::<Option<Vec<usize>>>()
+        .ok_or_else(|| SolveError::new("some cities are not connected"))
+}
+
+impl Day for Puzzle {
+    type Input = (Vec<String>, HashMap<(String, String), usize>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut cities = HashSet::new();
+        let mut distances = HashMap::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let (from, right) = line
+                .split_once(" to ")
+                .ok_or_else(|| ParseError::new(i + 1, 1, "missing ` to `"))?;
+            let (to, distance) = right
+                .split_once(" = ")
+                .ok_or_else(|| ParseError::at(i + 1, line, right, "missing ` = `"))?;
+            let distance = distance.parse::<usize>().map_err(|_| {
+                ParseError::at(
+                    i + 1,
+                    line,
+                    distance,
+                    format!("invalid distance: {}", distance),
+                )
+            })?;
+
+            let (from, to) = (from.to_string(), to.to_string());
+
+            cities.insert(from.clone());
+            cities.insert(to.clone());
+
+            distances.insert((from.clone(), to.clone()), distance);
+            distances.insert((to, from), distance);
+        }
+
+        Ok((cities.into_iter().collect(), distances))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        routes(input)?
+            .into_iter()
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no cities to visit"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        routes(input)?
+            .into_iter()
+            .max()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no cities to visit"))
+    }
+}
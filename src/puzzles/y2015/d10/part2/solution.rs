@@ -14,20 +14,22 @@ pub enum Digit {
     Nine,
 }
 
-impl From<char> for Digit {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Digit {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '0' => Self::Zero,
-            '1' => Self::One,
-            '2' => Self::Two,
-            '3' => Self::Three,
-            '4' => Self::Four,
-            '5' => Self::Five,
-            '6' => Self::Six,
-            '7' => Self::Seven,
-            '8' => Self::Eight,
-            '9' => Self::Nine,
-            _ => panic!("invalid digit"),
+            '0' => Ok(Self::Zero),
+            '1' => Ok(Self::One),
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            _ => Err(format!("invalid digit: {}", c)),
         }
     }
 }
@@ -82,14 +84,19 @@ impl Combination {
 impl Solution for Puzzle {
     type Input = Vec<Digit>;
 
-    fn parse(input: &str) -> Self::Input {
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
         input
             .chars()
-            .map(|c| Digit::from(c))
-            .collect::<Vec<Digit>>()
+            .enumerate()
+            .map(|(i, c)| Digit::try_from(c).map_err(|err| ParseError::new(1, i + 1, err)))
+            .collect::<Result<Vec<Digit>, ParseError>>()
+    }
+
+    fn solve(digits: Self::Input) -> Result<Answer, SolveError> {
+        Self::solve_with(digits, &Params::default())
     }
 
-    fn solve(digits: Self::Input) -> Option<String> {
+    fn solve_with(digits: Self::Input, params: &Params) -> Result<Answer, SolveError> {
         fn combine(digits: &[Digit]) -> Vec<Digit> {
             let mut combinations = Vec::<Combination>::new();
 
@@ -119,17 +126,22 @@ impl Solution for Puzzle {
 
             combinations
                 .iter()
-                .map(|combination| combination.spread())
-                .flatten()
+                .flat_map(|combination| combination.spread())
                 .collect::<Vec<Digit>>()
         }
 
+        if digits.is_empty() {
+            return Err(SolveError::new("no digits to look at"));
+        }
+
+        let iterations = params.get_or("iterations", 50)?;
+
         let mut output = digits;
 
-        for _ in 0..50 {
+        for _ in 0..iterations {
             output = combine(&output);
         }
 
-        Some(output.len().to_string())
+        Ok(output.len().into())
     }
 }
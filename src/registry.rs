@@ -0,0 +1,445 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::template::{Puzzle, Run, Source, SubmitOptions, Target, Verdict};
+
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub id: &'static str,
+    pub solve: fn(&Source) -> Result<(), String>,
+    pub submit: fn(&SubmitOptions) -> Result<(), String>,
+    pub examples: fn() -> Result<Verdict, String>,
+    pub run: fn(&str) -> Run,
+    // set for the parts of a day-level module, runs every part on a single parse
+    pub day: Option<fn(&str) -> Vec<Run>>,
+}
+
+impl Entry {
+    // the id is compiled from the module path, only the config can fail and it is loaded first
+    pub fn puzzle(&self) -> Puzzle {
+        let bytes = self.id.as_bytes();
+
+        Puzzle::new(
+            number(bytes, 0, 4),
+            number(bytes, 5, 7) as u8,
+            number(bytes, 8, 10) as u8,
+        )
+        .expect("config is loaded on startup")
+    }
+}
+
+pub fn all() -> impl Iterator<Item = &'static Entry> {
+    crate::puzzles::registry()
+        .into_iter()
+        .flat_map(|entries| entries.iter())
+}
+
+pub fn find(id: &str) -> Option<&'static Entry> {
+    all().find(|entry| entry.id == id)
+}
+
+// year -> day -> parts, a day without parts is a day-level module
+type Modules = BTreeMap<String, BTreeMap<String, Option<BTreeSet<String>>>>;
+
+// adds a puzzle module to the `puzzles!` block of a puzzles mod file, none if it is already listed
+pub fn register(source: &str, puzzle: &Puzzle, day_level: bool) -> Result<Option<String>, String> {
+    if !legacy(source)?.is_empty() {
+        return Err("puzzles are not grouped by year and day, run `aoc migrate` first".to_string());
+    }
+
+    let (start, end) = block(source)?;
+    let mut modules = parse(&source[start + 1..end])?;
+
+    let year = format!("y{}", puzzle.year);
+    let day = format!("d{}", puzzle.day);
+    let part = format!("part{}", puzzle.part.trim_start_matches('0'));
+
+    let days = modules.entry(year).or_default();
+
+    match (days.get_mut(&day), day_level) {
+        (Some(None), true) => {}
+        (Some(Some(_)), true) => {
+            return Err(format!("{} already has part modules", puzzle.day_module));
+        }
+        (Some(None), false) => {
+            return Err(format!("{} is a day-level module", puzzle.day_module));
+        }
+        (Some(Some(parts)), false) => {
+            parts.insert(part);
+        }
+        (None, true) => {
+            days.insert(day, None);
+        }
+        (None, false) => {
+            days.insert(day, Some(BTreeSet::from([part])));
+        }
+    }
+
+    let rendered = format!(
+        "{}{}{}",
+        &source[..start + 1],
+        render(&modules),
+        &source[end..]
+    );
+
+    match rendered == source {
+        true => Ok(None),
+        false => Ok(Some(rendered)),
+    }
+}
+
+// the flat `_YYYY_DD_PP` modules of a puzzles mod file with their grouped module paths
+pub fn legacy(source: &str) -> Result<Vec<(String, String)>, String> {
+    let (start, end) = block(source)?;
+
+    Ok(tokens(&source[start + 1..end])?
+        .into_iter()
+        .filter_map(|token| legacy_module(&token).map(|module| (token, module)))
+        .collect())
+}
+
+// rewrites the `puzzles!` block sorted and grouped by year and day
+pub fn normalize(source: &str) -> Result<String, String> {
+    let (start, end) = block(source)?;
+    let modules = parse(&source[start + 1..end])?;
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start + 1],
+        render(&modules),
+        &source[end..]
+    ))
+}
+
+fn block(source: &str) -> Result<(usize, usize), String> {
+    let start = source
+        .find("puzzles!")
+        .and_then(|start| source[start..].find('{').map(|brace| start + brace))
+        .ok_or("failed to find the puzzles registry")?;
+
+    let end = source
+        .rfind('}')
+        .filter(|&end| end > start)
+        .ok_or("failed to find the end of the puzzles registry")?;
+
+    Ok((start, end))
+}
+
+fn tokens(block: &str) -> Result<Vec<String>, String> {
+    let mut tokens = vec![];
+    let mut chars = block.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '{' | '}' | ',' => {
+                tokens.push(c.to_string());
+                chars.next();
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = String::new();
+
+                while let Some(&c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                    ident.push(c);
+                    chars.next();
+                }
+
+                tokens.push(ident);
+            }
+            c => {
+                return Err(format!(
+                    "unexpected character in the puzzles registry: {}",
+                    c
+                ))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Node {
+    ident: String,
+    children: Option<Vec<Node>>,
+}
+
+type Tokens = std::iter::Peekable<std::vec::IntoIter<String>>;
+
+fn nodes(tokens: &mut Tokens) -> Result<Vec<Node>, String> {
+    let mut siblings = vec![];
+
+    while let Some(ident) = tokens.next() {
+        match ident.as_str() {
+            "}" => return Ok(siblings),
+            "," => continue,
+            "{" => return Err("unexpected `{` in the puzzles registry".to_string()),
+            _ => {}
+        }
+
+        let children = match tokens.next_if_eq("{") {
+            Some(_) => Some(nodes(tokens)?),
+            None => None,
+        };
+
+        siblings.push(Node { ident, children });
+    }
+
+    Ok(siblings)
+}
+
+fn parse(block: &str) -> Result<Modules, String> {
+    let mut modules = Modules::new();
+
+    for year in nodes(&mut tokens(block)?.into_iter().peekable())? {
+        let Some(days) = year.children else {
+            // a flat module from before puzzles were grouped by year and day
+            let module = legacy_module(&year.ident)
+                .ok_or_else(|| format!("invalid module in the puzzles registry: {}", year.ident))?;
+
+            let path = module.split("::").map(str::to_string).collect::<Vec<_>>();
+            let days = modules.entry(path[0].clone()).or_default();
+
+            match path.get(2) {
+                Some(part) => {
+                    days.entry(path[1].clone())
+                        .or_insert_with(|| Some(BTreeSet::new()))
+                        .get_or_insert_with(BTreeSet::new)
+                        .insert(part.clone());
+                }
+                None => {
+                    days.insert(path[1].clone(), None);
+                }
+            }
+
+            continue;
+        };
+
+        let entry = modules.entry(year.ident.clone()).or_default();
+
+        for day in days {
+            let parts = match day.children {
+                Some(parts) => Some(
+                    parts
+                        .into_iter()
+                        .map(|part| match part.children {
+                            Some(_) => Err(format!("{} is nested too deeply", part.ident)),
+                            None => Ok(part.ident),
+                        })
+                        .collect::<Result<BTreeSet<_>, _>>()?,
+                ),
+                None => None,
+            };
+
+            entry.insert(day.ident, parts);
+        }
+    }
+
+    Ok(modules)
+}
+
+fn legacy_module(ident: &str) -> Option<String> {
+    let numbers = ident
+        .strip_prefix('_')?
+        .split('_')
+        .map(|n| {
+            n.parse::<u16>()
+                .ok()
+                .filter(|_| n.len() == 2 || n.len() == 4)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    match numbers[..] {
+        [year, day] => Some(format!("y{:04}::d{:02}", year, day)),
+        [year, day, part] => Some(format!("y{:04}::d{:02}::part{}", year, day, part)),
+        _ => None,
+    }
+}
+
+fn render(modules: &Modules) -> String {
+    let mut block = String::from("\n");
+
+    for (year, days) in modules.iter().filter(|(_, days)| !days.is_empty()) {
+        block.push_str(&format!("    {} {{\n", year));
+
+        for (day, parts) in days {
+            match parts {
+                Some(parts) => {
+                    let parts = parts.iter().cloned().collect::<Vec<_>>().join(", ");
+                    block.push_str(&format!("        {} {{ {} }},\n", day, parts));
+                }
+                None => block.push_str(&format!("        {},\n", day)),
+            }
+        }
+
+        block.push_str("    },\n");
+    }
+
+    block
+}
+
+// whether a day id, i.e 2015_25, has a second part, evaluated when a day module is compiled
+pub const fn second_part(day_id: &str) -> bool {
+    let bytes = day_id.as_bytes();
+
+    if bytes.len() != 7 {
+        return true;
+    }
+
+    let year = number(bytes, 0, 4);
+    let day = number(bytes, 5, 7);
+
+    Target::parts(year, day as u8) == 2
+}
+
+const fn number(bytes: &[u8], mut from: usize, to: usize) -> u16 {
+    let mut number = 0;
+
+    while from < to {
+        number = number * 10 + (bytes[from] - b'0') as u16;
+        from += 1;
+    }
+
+    number
+}
+
+#[macro_export]
+macro_rules! entry {
+    ($id:expr, $solution:path) => {
+        $crate::entry!(@entry $id, $solution, None)
+    };
+    ($id:expr, $solution:path, $day:expr) => {
+        $crate::entry!(@entry $id, $solution, Some($day))
+    };
+    (@entry $id:expr, $solution:path, $day:expr) => {
+        $crate::registry::Entry {
+            id: $id,
+            solve: |source| $crate::template::Runner::solve_from($solution, source),
+            submit: |options| $crate::template::Runner::submit($solution, options),
+            examples: || $crate::template::Runner::report($solution),
+            run: $crate::template::Runner::run::<$solution>,
+            day: $day,
+        }
+    };
+}
+
+// puzzles are grouped as `year { day { part, .. }, day, .. }`, a day without parts is a
+// day-level module; flat `_YYYY_DD_PP` modules are still accepted until they are migrated
+#[macro_export]
+macro_rules! puzzles {
+    (@module $module:ident) => {
+        pub mod $module;
+    };
+    (@module $year:ident { $($day:ident $({ $($part:ident),* $(,)? })?),* $(,)? }) => {
+        pub mod $year {
+            $($crate::puzzles!(@day $day $({ $($part),* })?);)*
+        }
+    };
+    (@day $day:ident) => {
+        pub mod $day;
+    };
+    (@day $day:ident { $($part:ident),* }) => {
+        pub mod $day {
+            $(pub mod $part;)*
+        }
+    };
+    (@entries $registry:ident $module:ident) => {
+        $registry.push($module::solution::ENTRIES);
+    };
+    (@entries $registry:ident $year:ident { $($day:ident $({ $($part:ident),* $(,)? })?),* $(,)? }) => {
+        $($crate::puzzles!(@entries $registry $year $day $({ $($part),* })?);)*
+    };
+    (@entries $registry:ident $year:ident $day:ident) => {
+        $registry.push($year::$day::solution::ENTRIES);
+    };
+    (@entries $registry:ident $year:ident $day:ident { $($part:ident),* }) => {
+        $($registry.push($year::$day::$part::solution::ENTRIES);)*
+    };
+    ($($item:ident $({ $($days:tt)* })?),* $(,)?) => {
+        $($crate::puzzles!(@module $item $({ $($days)* })?);)*
+
+        #[allow(clippy::vec_init_then_push)]
+        pub fn registry() -> Vec<&'static [$crate::registry::Entry]> {
+            let mut registry = vec![];
+            $($crate::puzzles!(@entries registry $item $({ $($days)* })?);)*
+            registry
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_day_has_no_second_part() {
+        assert!(second_part("2015_24"));
+        assert!(!second_part("2015_25"));
+        assert!(second_part("2025_11"));
+        assert!(!second_part("2025_12"));
+    }
+
+    fn puzzle(id: &str) -> Puzzle {
+        Puzzle::parse(id).expect("failed to parse puzzle")
+    }
+
+    #[test]
+    fn register_groups_modules_by_year_and_day() {
+        let source = "crate::puzzles! {\n}\n";
+
+        let source = register(source, &puzzle("2016_02_01"), false)
+            .expect("failed to register")
+            .expect("puzzle is not registered yet");
+        let source = register(&source, &puzzle("2015_07_02"), false)
+            .expect("failed to register")
+            .expect("puzzle is not registered yet");
+        let source = register(&source, &puzzle("2015_07_01"), false)
+            .expect("failed to register")
+            .expect("puzzle is not registered yet");
+        let source = register(&source, &puzzle("2015_06_01"), true)
+            .expect("failed to register")
+            .expect("puzzle is not registered yet");
+
+        assert_eq!(
+            source,
+            "crate::puzzles! {\n    y2015 {\n        d06,\n        d07 { part1, part2 },\n    },\n    y2016 {\n        d02 { part1 },\n    },\n}\n"
+        );
+        assert_eq!(register(&source, &puzzle("2015_07_01"), false), Ok(None));
+        assert_eq!(register(&source, &puzzle("2015_06_02"), true), Ok(None));
+        assert!(register(&source, &puzzle("2015_06_01"), false).is_err());
+        assert!(register(&source, &puzzle("2015_07_01"), true).is_err());
+    }
+
+    #[test]
+    fn entries_of_an_event_not_started_yet_are_valid() {
+        let now = chrono::Utc::now().naive_utc() - chrono::Duration::hours(5);
+        let id = format!("{}_12_01", chrono::Datelike::year(&now));
+
+        let entry = Entry {
+            id: Box::leak(id.into_boxed_str()),
+            solve: |_| Ok(()),
+            submit: |_| Ok(()),
+            examples: || Ok(Verdict::Unverified),
+            run: |_| unreachable!(),
+            day: None,
+        };
+
+        assert_eq!(entry.puzzle().id, entry.id);
+        assert!(Target::parse(entry.id).is_ok());
+    }
+
+    #[test]
+    fn legacy_modules_are_migrated_first() {
+        let source = "crate::puzzles! {\n    _2015_06,\n    _2015_01_02,\n}\n";
+
+        assert_eq!(
+            legacy(source),
+            Ok(vec![
+                ("_2015_06".to_string(), "y2015::d06".to_string()),
+                ("_2015_01_02".to_string(), "y2015::d01::part2".to_string()),
+            ])
+        );
+        assert!(register(source, &puzzle("2015_02_01"), false).is_err());
+        assert!(register("mod puzzles;", &puzzle("2015_02_01"), false).is_err());
+    }
+}
@@ -1,8 +1,17 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 
-use aoc_client::{AocClient, SubmissionOutcome};
+use aoc_client::SubmissionOutcome;
+use chrono::Datelike;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::client::Hint;
+use crate::error::{Error, ParseError, SolveError};
+use crate::{config, provider};
+
 #[derive(Clone)]
 pub struct Puzzle {
     pub id: String,
@@ -11,16 +20,44 @@ pub struct Puzzle {
     pub part: String,
     pub bin_file_path: String,
     pub dir_path: String,
-    pub dir_name: String,
+    pub module: String,
+    pub day_dir_path: String,
+    pub day_module: String,
+    pub profile: Option<String>,
     pub description_path: String,
     pub input_path: String,
+    pub answers_path: String,
     pub examples_path: String,
     pub mod_file_path: String,
     pub solution_path: String,
+    pub metadata_path: String,
 }
 
-#[derive(Clone)]
-pub struct Metadata {}
+// what is known about a puzzle day, kept next to the answers to list and search puzzles by
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub url: String,
+    pub released: String,
+    pub parts: BTreeMap<String, Progress>,
+    pub tags: BTreeSet<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Progress {
+    #[default]
+    Unsolved,
+    // an answer was rejected
+    Attempted,
+    Starred,
+}
 
 impl Display for Puzzle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -30,43 +67,20 @@ impl Display for Puzzle {
 
 impl Puzzle {
     pub fn parse(input: &str) -> Result<Self, String> {
-        let parts: Vec<_> = input.split('_').collect();
-
-        if parts.len() != 3 {
-            return Err("target must be in the format event:day:part, i.e 2015_01_01".to_string());
-        }
+        let mut puzzles = Target::parse(input)?.puzzles;
 
-        if parts[0].len() != 4 {
-            return Err("event must be a 4 digit number".to_string());
+        if puzzles.len() != 1 {
+            return Err("target is a whole day, a part is required, i.e 2015_01_01".to_string());
         }
 
-        if parts[1].len() > 2 {
-            return Err("day must be a 2 digit number".to_string());
-        }
-
-        if parts[2].len() > 2 {
-            return Err("part must be a 2 digit number".to_string());
-        }
-
-        let event_year_n = parts[0]
-            .parse::<u16>()
-            .map_err(|_| "event must be a number")?;
-
-        let event_day_n = parts[1].parse::<u8>().map_err(|_| "day must be a number")?;
-
-        let puzzle_part_n = parts[2]
-            .parse::<u8>()
-            .map_err(|_| "part must be a number")?;
-
-        if puzzle_part_n > 2 || puzzle_part_n < 1 {
-            return Err("part must be between 1 and 2".to_string());
-        }
+        Ok(puzzles.remove(0))
+    }
 
-        if event_day_n > 25 || event_day_n < 1 {
-            return Err("day must be between 1 and 25".to_string());
-        }
+    pub fn new(event_year_n: u16, event_day_n: u8, puzzle_part_n: u8) -> Result<Self, String> {
+        let config = config::get()?;
+        let paths = &config.paths;
 
-        Ok(Self {
+        let puzzle = Self {
             id: format!(
                 "{:04}_{:02}_{:02}",
                 event_year_n, event_day_n, puzzle_part_n
@@ -75,119 +89,938 @@ impl Puzzle {
             day: format!("{:02}", event_day_n),
             part: format!("{:02}", puzzle_part_n),
             bin_file_path: format!(
-                "src/bin/{:04}_{:02}_{:02}.rs",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/{:04}_{:02}_{:02}.rs",
+                config::BINS_DIR,
+                event_year_n,
+                event_day_n,
+                puzzle_part_n
             ),
             dir_path: format!(
-                "src/puzzles/_{:04}_{:02}_{:02}",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/y{:04}/d{:02}/part{}",
+                config::PUZZLES_DIR,
+                event_year_n,
+                event_day_n,
+                puzzle_part_n
             ),
-            dir_name: format!(
-                "_{:04}_{:02}_{:02}",
+            module: format!(
+                "y{:04}::d{:02}::part{}",
                 event_year_n, event_day_n, puzzle_part_n
             ),
+            day_dir_path: format!(
+                "{}/y{:04}/d{:02}",
+                config::PUZZLES_DIR,
+                event_year_n,
+                event_day_n
+            ),
+            day_module: format!("y{:04}::d{:02}", event_year_n, event_day_n),
+            profile: None,
             description_path: format!(
-                "src/puzzles/_{:04}_{:02}_{:02}/description.md",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/y{:04}/d{:02}/part{}/description.md",
+                config::PUZZLES_DIR,
+                event_year_n,
+                event_day_n,
+                puzzle_part_n
             ),
-            input_path: format!("data/inputs/{:04}_{:02}.txt", event_year_n, event_day_n),
+            input_path: String::new(),
+            answers_path: String::new(),
             examples_path: format!(
-                "data/examples/{:04}_{:02}_{:02}.json",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/{:04}_{:02}_{:02}.json",
+                paths.examples, event_year_n, event_day_n, puzzle_part_n
             ),
             mod_file_path: format!(
-                "src/puzzles/_{:04}_{:02}_{:02}/mod.rs",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/y{:04}/d{:02}/part{}/mod.rs",
+                config::PUZZLES_DIR,
+                event_year_n,
+                event_day_n,
+                puzzle_part_n
             ),
             solution_path: format!(
-                "src/puzzles/_{:04}_{:02}_{:02}/solution.rs",
-                event_year_n, event_day_n, puzzle_part_n
+                "{}/y{:04}/d{:02}/part{}/solution.rs",
+                config::PUZZLES_DIR,
+                event_year_n,
+                event_day_n,
+                puzzle_part_n
             ),
+            metadata_path: String::new(),
+        };
+
+        puzzle.with_profile(config.profile.as_deref())
+    }
+
+    // inputs, answers and the stars they earn belong to an account, a profile keeps them in
+    // their own dirs
+    pub fn with_profile(&self, profile: Option<&str>) -> Result<Self, String> {
+        let paths = &config::get()?.paths;
+
+        if let Some(profile) = profile {
+            config::validate_profile(profile)?;
+        }
+
+        let dir = |root: &str| config::profile_dir(root, profile);
+
+        Ok(Self {
+            profile: profile.map(str::to_string),
+            input_path: format!("{}/{}_{}.txt", dir(&paths.inputs), self.year, self.day),
+            answers_path: format!("{}/{}_{}.json", dir(&paths.answers), self.year, self.day),
+            metadata_path: format!("{}/{}_{}.json", dir(&paths.metadata), self.year, self.day),
+            ..self.clone()
         })
     }
+
+    // puzzles unlock at midnight EST on their day of december
+    pub fn unlocked(&self) -> bool {
+        let (Ok(year), Ok(day)) = (self.year.parse(), self.day.parse()) else {
+            return false;
+        };
+
+        chrono::NaiveDate::from_ymd_opt(year, 12, day)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .is_some_and(|unlock| unlock <= Target::now())
+    }
+}
+
+// a single part or a whole day, i.e 2015_07_02, 2015/7/2, 2015-07, 15.7 or today
+#[derive(Clone)]
+pub struct Target {
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl Target {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let (year, day, part) = Target::id(input)?;
+
+        let latest = Target::latest_event();
+
+        if !(2015..=latest).contains(&year) {
+            return Err(format!("event must be between 2015 and {}", latest));
+        }
+
+        let days = Target::days(year);
+
+        if !(1..=days).contains(&day) {
+            return Err(format!("day must be between 1 and {}", days));
+        }
+
+        if part.is_some_and(|part| !(1..=2).contains(&part)) {
+            return Err("part must be between 1 and 2".to_string());
+        }
+
+        let parts = Target::parts(year, day);
+
+        if part.is_some_and(|part| part > parts) {
+            return Err(format!("day {} of {} has a single part", day, year));
+        }
+
+        let parts = match part {
+            Some(part) => vec![part],
+            None => (1..=parts).collect(),
+        };
+
+        Ok(Self {
+            puzzles: parts
+                .into_iter()
+                .map(|part| Puzzle::new(year, day, part))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    // every part of the day of a puzzle
+    pub fn day(puzzle: &Puzzle) -> Result<Self, String> {
+        let (Ok(year), Ok(day)) = (puzzle.year.parse(), puzzle.day.parse()) else {
+            return Err(format!("puzzle[{}]: invalid event or day", puzzle));
+        };
+
+        Ok(Self {
+            puzzles: (1..=Target::parts(year, day))
+                .map(|part| Puzzle::new(year, day, part))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    // a whole event, i.e 2016, can be prepared before it starts
+    pub fn event(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+
+        if input.len() != 4 || !input.chars().all(|c| c.is_ascii_digit()) {
+            return Target::parse(input);
+        }
+
+        let year = input.parse::<u16>().map_err(|_| "event must be a number")?;
+        let latest = Target::latest_event();
+
+        if !(2015..=latest).contains(&year) {
+            return Err(format!("event must be between 2015 and {}", latest));
+        }
+
+        Ok(Self {
+            puzzles: (1..=Target::days(year))
+                .flat_map(|day| {
+                    (1..=Target::parts(year, day)).map(move |part| Puzzle::new(year, day, part))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn id(input: &str) -> Result<(u16, u8, Option<u8>), String> {
+        Target::id_at(input, config::get()?.year, Target::now())
+    }
+
+    fn id_at(
+        input: &str,
+        default: Option<u16>,
+        now: chrono::NaiveDateTime,
+    ) -> Result<(u16, u8, Option<u8>), String> {
+        let input = input.trim().to_lowercase();
+
+        if input == "today" {
+            let (year, day) = Target::today(now)?;
+            return Ok((year, day, None));
+        }
+
+        let segments: Vec<_> = input.split(['_', '/', '-', '.', ':']).collect();
+
+        if segments
+            .iter()
+            .any(|segment| segment.is_empty() || !segment.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(
+                "target must be in the format event:day:part, i.e 2015_01_01, 2015/1/1, 15.1 or today"
+                    .to_string(),
+            );
+        }
+
+        let year = |segment: &str| match segment.len() {
+            4 => segment.parse::<u16>().map_err(|_| "event must be a number"),
+            2 => Ok(2000
+                + segment
+                    .parse::<u16>()
+                    .map_err(|_| "event must be a number")?),
+            _ => Err("event must be a 2 or 4 digit number"),
+        };
+
+        let day = |segment: &str| match segment.len() {
+            1 | 2 => segment.parse::<u8>().map_err(|_| "day must be a number"),
+            _ => Err("day must be a 2 digit number"),
+        };
+
+        let part = |segment: &str| match segment.len() {
+            1 | 2 => segment.parse::<u8>().map_err(|_| "part must be a number"),
+            _ => Err("part must be a 2 digit number"),
+        };
+
+        // the configured default year allows omitting the event, i.e 07_01 or 7. two short
+        // segments are a day and part when the second one can be a part, so 15.1 is day 15 of
+        // the default event and 15.7 is day 7 of 2015
+        let is_part = |segment: &str| matches!(segment.parse::<u8>(), Ok(1 | 2));
+
+        let id = match (segments.as_slice(), default) {
+            ([y, d, p], _) => (year(y)?, day(d)?, Some(part(p)?)),
+            ([y, d], _) if y.len() == 4 => (year(y)?, day(d)?, None),
+            ([d, p], Some(default)) if is_part(p) => (default, day(d)?, Some(part(p)?)),
+            ([y, d], Some(_)) => (year(y)?, day(d)?, None),
+            ([y, d], None) => (year(y)?, day(d)?, None),
+            ([d], Some(default)) => (default, day(d)?, None),
+            _ => {
+                return Err(
+                    "target must be in the format event:day:part, i.e 2015_01_01".to_string(),
+                )
+            }
+        };
+
+        Ok(id)
+    }
+
+    // events run for 25 days, from 2025 on they run for 12
+    pub const fn days(year: u16) -> u8 {
+        match year {
+            ..=2024 => 25,
+            _ => 12,
+        }
+    }
+
+    // the last day of an event has a single puzzle
+    pub const fn parts(year: u16, day: u8) -> u8 {
+        match day == Target::days(year) {
+            true => 1,
+            false => 2,
+        }
+    }
+
+    // puzzles unlock at midnight EST (UTC-5)
+    fn now() -> chrono::NaiveDateTime {
+        chrono::Utc::now().naive_utc() - chrono::Duration::hours(5)
+    }
+
+    // the event of the current year can be scaffolded and registered before it starts, its
+    // puzzles stay locked until their day
+    fn latest_event() -> u16 {
+        Target::now().year() as u16
+    }
+
+    fn today(now: chrono::NaiveDateTime) -> Result<(u16, u8), String> {
+        let year = now.year() as u16;
+
+        match (now.month(), now.day()) {
+            (12, day) if day <= Target::days(year) as u32 => Ok((year, day as u8)),
+            _ => Err("no puzzle is released today".to_string()),
+        }
+    }
+}
+
+impl Metadata {
+    pub fn load(puzzle: &Puzzle) -> Result<Self, String> {
+        match std::fs::read_to_string(&puzzle.metadata_path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|err| format!("unable to parse {}: {}", puzzle.metadata_path, err)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, puzzle: &Puzzle) -> Result<(), String> {
+        if let Some(dir) = std::path::Path::new(&puzzle.metadata_path).parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("unable to create {}: {}", dir.display(), err))?;
+        }
+
+        std::fs::write(&puzzle.metadata_path, self.to_json())
+            .map_err(|err| format!("unable to write {}: {}", puzzle.metadata_path, err))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("unable to serialize metadata")
+    }
+
+    // fills in what the site tells about the puzzle, keeping what was written by hand
+    pub fn refresh(&mut self, puzzle: &Puzzle, title: Option<String>) -> Result<(), String> {
+        let day = puzzle.day.trim_start_matches('0');
+
+        self.title = title.or(self.title.take());
+        self.url = format!("https://adventofcode.com/{}/day/{}", puzzle.year, day);
+        self.released = format!("{}-12-{}T00:00:00-05:00", puzzle.year, puzzle.day);
+
+        let parts = match (puzzle.year.parse(), puzzle.day.parse()) {
+            (Ok(year), Ok(day)) => Target::parts(year, day),
+            _ => 2,
+        };
+
+        for part in 1..=parts {
+            self.parts.entry(format!("{:02}", part)).or_default();
+        }
+
+        let answers = Answers::load(puzzle)?;
+
+        for (part, record) in answers.parts.iter() {
+            if record.accepted.is_some() {
+                self.parts.insert(part.clone(), Progress::Starred);
+            }
+        }
+
+        Ok(())
+    }
+
+    // a part only ever moves forward, a failing solve does not take its star away
+    pub fn progress(puzzle: &Puzzle, progress: Progress) -> Result<(), String> {
+        let mut metadata = Metadata::load(puzzle)?;
+        let current = metadata.parts.entry(puzzle.part.clone()).or_default();
+
+        if *current < progress {
+            *current = progress;
+
+            if metadata.url.is_empty() {
+                metadata.refresh(puzzle, None)?;
+            }
+
+            metadata.save(puzzle)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn stars(&self) -> usize {
+        self.parts
+            .values()
+            .filter(|progress| **progress == Progress::Starred)
+            .count()
+    }
+
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+
+        [&self.title, &self.difficulty, &self.notes]
+            .into_iter()
+            .flatten()
+            .chain(self.tags.iter())
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsolved => write!(f, "unsolved"),
+            Self::Attempted => write!(f, "attempted"),
+            Self::Starred => write!(f, "starred"),
+        }
+    }
 }
 
 pub trait Solution {
     type Input;
 
-    fn parse(input: &str) -> Self::Input;
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn solve(input: Self::Input) -> Result<Answer, SolveError>;
 
-    fn solve(input: Self::Input) -> Option<String>;
+    fn solve_with(input: Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Self::solve(input)
+    }
+}
+
+pub trait Day {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part1_with(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Self::part2(input)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, serde_json::Value>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T, SolveError> {
+        match self.0.get(key) {
+            Some(value) => serde_json::from_value(value.clone())
+                .map_err(|err| SolveError::new(format!("invalid param {}: {}", key, err))),
+            None => Ok(default),
+        }
+    }
 }
 
 pub struct Runner;
 
+#[derive(Debug, Default, Clone)]
+pub struct SubmitOptions {
+    pub wait: bool,
+    pub force: bool,
+}
+
+// the outcome of checking a solution against its examples, unverified when no example has a known answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Passed,
+    Failed,
+    Unverified,
+}
+
+impl Verdict {
+    fn new(passed: usize, failed: usize) -> Self {
+        match (passed, failed) {
+            (_, 1..) => Self::Failed,
+            (0, _) => Self::Unverified,
+            _ => Self::Passed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub enum Source {
+    #[default]
+    Puzzle,
+    File(String),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    pub fn read(&self, puzzle: &Puzzle) -> Result<String, String> {
+        match self {
+            Self::Puzzle => std::fs::read_to_string(&puzzle.input_path)
+                .map_err(|err| format!("unable to read {}: {}", puzzle.input_path, err)),
+            Self::File(path) => std::fs::read_to_string(path)
+                .map_err(|err| format!("unable to read {}: {}", path, err)),
+            Self::Stdin => std::io::read_to_string(std::io::stdin())
+                .map_err(|err| format!("unable to read stdin: {}", err)),
+            Self::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Puzzle => write!(f, "puzzle"),
+            Self::File(path) => write!(f, "{}", path),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Inline(_) => write!(f, "inline"),
+        }
+    }
+}
+
+pub struct Run {
+    pub answer: Result<Answer, Error>,
+    pub parse_time: String,
+    pub solve_time: String,
+}
+
 impl Runner {
     pub fn solve<T: Solution + Display>(name: T) {
-        let puzzle = Puzzle::parse(&name.to_string())
-            .expect("unable to parse puzzle, expected format: event:day:part");
-
-        let input = std::fs::read_to_string(&puzzle.input_path).expect("unable to read input");
-        let parsed = T::parse(&input.trim_end());
-        let (output, time) = Runner::timed(|| T::solve(parsed).expect("unable to solve solution"));
-
-        let client = AocClient::builder()
-            .session_cookie_from_default_locations()
-            .expect("failed to get session cookie from default locations on client build")
-            .year(puzzle.year.parse().expect("failed to parse year"))
-            .expect("failed to set year on client build")
-            .day(puzzle.day.parse().expect("failed to parse day"))
-            .expect("failed to set day on client build")
-            .build()
-            .expect("failed to build aoc client");
-
-        let part = match puzzle.part.as_str() {
-            "01" => 1,
-            "02" => 2,
-            _ => panic!("invalid part"),
-        };
-
-        let outcome = client
-            .submit_answer(part, &output)
-            .expect("failed to submit answer");
-
-        match outcome {
-            SubmissionOutcome::Correct => {
+        if let Err(err) = Runner::solve_from(name, &Source::Puzzle) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+
+    pub fn solve_from<T: Solution + Display>(name: T, source: &Source) -> Result<(), String> {
+        let puzzle = Puzzle::parse(&name.to_string())?;
+        let (output, time) = Runner::answer::<T>(&puzzle, source)
+            .map_err(|err| format!("puzzle[{}]: {}", name, err))?;
+
+        Runner::solved(&puzzle, &output, &time, source)
+    }
+
+    // solves both parts of a day-level module, parsing the input once
+    pub fn solve_day(
+        puzzles: &[Puzzle],
+        run: fn(&str) -> Vec<Run>,
+        source: &Source,
+    ) -> Result<(), String> {
+        let Some(first) = puzzles.first() else {
+            return Ok(());
+        };
+
+        let input = source
+            .read(first)
+            .map_err(|err| format!("puzzle[{}]: {}", first, Error::Input(err)))?;
+
+        let mut errors = Vec::new();
+
+        for (puzzle, run) in puzzles.iter().zip(run(&input)) {
+            match run.answer {
+                Ok(output) => Runner::solved(puzzle, &output, &run.solve_time, source)?,
+                Err(err) => errors.push(format!("puzzle[{}]: {}", puzzle, err)),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors.join("\n")),
+        }
+    }
+
+    fn solved(puzzle: &Puzzle, output: &Answer, time: &str, source: &Source) -> Result<(), String> {
+        let name = &puzzle.id;
+
+        match source {
+            Source::Puzzle => {
+                println!("puzzle[{}]: answer={} time={}", name, output, time);
+
+                // a run proves nothing by itself, only the answer the site accepted earns the star
+                let answers = Answers::load(puzzle)?;
+
+                let accepted = answers
+                    .accepted(puzzle)
+                    .is_some_and(|accepted| output.submission().as_ref() == Ok(accepted));
+
+                match accepted {
+                    true => Metadata::progress(puzzle, Progress::Starred),
+                    false => Ok(()),
+                }
+            }
+            source => {
                 println!(
-                    "puzzle[{}]: submit answer={} time={} status=correct",
-                    name, output, time
+                    "puzzle[{}]: answer={} time={} input={}",
+                    name, output, time, source
                 );
+                Ok(())
             }
-            SubmissionOutcome::Incorrect => {
+        }
+    }
+
+    pub fn submit<T: Solution + Display>(name: T, options: &SubmitOptions) -> Result<(), String> {
+        let puzzle = Puzzle::parse(&name.to_string())?;
+        let (output, time) = Runner::answer::<T>(&puzzle, &Source::Puzzle)
+            .map_err(|err| format!("puzzle[{}]: {}", name, err))?;
+
+        // ascii art is only ever submitted as the letters it reads
+        let output = match output.submission() {
+            Ok(output) => output,
+            Err(reason) => {
                 println!(
-                    "puzzle[{}]: submit answer={} time={} status=incorrect",
-                    name, output, time
+                    "puzzle[{}]: submit time={} status=unreadable reason={}",
+                    name, time, reason
                 );
+                return Ok(());
             }
-            SubmissionOutcome::Wait => {
+        };
+
+        if let Some(reason) = output.suspicious() {
+            if !options.force {
                 println!(
-                    "puzzle[{}]: submit answer={} time={} status=wait",
-                    name, output, time
+                    "puzzle[{}]: submit answer={} time={} status=suspicious reason={}",
+                    name, output, time, reason
                 );
+                return Ok(());
             }
-            SubmissionOutcome::WrongLevel => {
+        }
+
+        let mut answers = Answers::load(&puzzle)?;
+
+        if let Err(reason) = answers.check(&puzzle, &output) {
+            println!(
+                "puzzle[{}]: submit answer={} time={} status=blocked reason={}",
+                name, output, time, reason
+            );
+            return Ok(());
+        }
+
+        if let Some(remaining) = answers.cooldown(&puzzle) {
+            if !options.wait {
                 println!(
-                    "puzzle[{}]: submit answer={} time={} status=wrong-level",
-                    name, output, time
+                    "puzzle[{}]: submit answer={} time={} status=cooldown remaining={}",
+                    name,
+                    output,
+                    time,
+                    Runner::remaining(remaining)
                 );
+                return Ok(());
+            }
+
+            println!(
+                "puzzle[{}]: waiting for cooldown remaining={}",
+                name,
+                Runner::remaining(remaining)
+            );
+
+            std::thread::sleep(remaining);
+        }
+
+        let submission = provider::current()
+            .and_then(|provider| provider.submit(&puzzle, &output.to_string()))
+            .map_err(|err| {
+                format!(
+                    "puzzle[{}]: answer={} time={} unable to submit: {}",
+                    name, output, time, err
+                )
+            })?;
+
+        answers.submitted(&puzzle, &output, &submission);
+        answers.save(&puzzle)?;
+
+        match submission.outcome {
+            SubmissionOutcome::Correct => Metadata::progress(&puzzle, Progress::Starred)?,
+            SubmissionOutcome::Incorrect => Metadata::progress(&puzzle, Progress::Attempted)?,
+            _ => {}
+        }
+
+        if let Some(wait) = submission.wait {
+            println!(
+                "puzzle[{}]: cooldown remaining={}",
+                name,
+                Runner::remaining(wait)
+            );
+        }
+
+        let status = match submission.outcome {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::Wait => "wait",
+            SubmissionOutcome::WrongLevel => "wrong-level",
+        };
+
+        match submission.hint {
+            Some(Hint::TooHigh) => println!(
+                "puzzle[{}]: submit answer={} time={} status={} hint=too-high",
+                name, output, time, status
+            ),
+            Some(Hint::TooLow) => println!(
+                "puzzle[{}]: submit answer={} time={} status={} hint=too-low",
+                name, output, time, status
+            ),
+            None => println!(
+                "puzzle[{}]: submit answer={} time={} status={}",
+                name, output, time, status
+            ),
+        }
+
+        // solving part one unlocks the description of part two
+        if matches!(submission.outcome, SubmissionOutcome::Correct) && puzzle.part == "01" {
+            Runner::descriptions(&puzzle);
+        }
+
+        Ok(())
+    }
+
+    fn descriptions(puzzle: &Puzzle) {
+        // the last day of an event has no second part
+        let second = Puzzle::parse(&format!("{}_{}_02", puzzle.year, puzzle.day)).ok();
+
+        let provider = match provider::current() {
+            Ok(provider) => provider,
+            Err(err) => {
+                eprintln!("puzzle[{}]: unable to update description: {}", puzzle, err);
+                return;
+            }
+        };
+
+        let dirs: Vec<&String> = [Some(puzzle), second.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|puzzle| &puzzle.dir_path)
+            .chain([&puzzle.day_dir_path])
+            .filter(|dir| std::path::Path::new(dir).exists())
+            .collect();
+
+        if dirs.is_empty() {
+            return;
+        }
+
+        // the description covers the whole day, it is fetched once for every dir
+        let description = match provider.description(puzzle) {
+            Ok(description) => description,
+            Err(err) => {
+                eprintln!("puzzle[{}]: unable to update description: {}", puzzle, err);
+                return;
+            }
+        };
+
+        for dir in dirs {
+            let path = format!("{}/description.md", dir);
+
+            match std::fs::write(&path, &description) {
+                Ok(()) => println!("puzzle[{}]: description updated path={}", puzzle, path),
+                Err(err) => eprintln!(
+                    "puzzle[{}]: unable to update description: unable to write {}: {}",
+                    puzzle, path, err
+                ),
             }
         }
     }
 
     pub fn examples<T: Solution + Display>(name: T) {
-        let puzzle = Puzzle::parse(&name.to_string())
-            .expect("unable to parse puzzle, expected format: event:day:part");
-
-        let examples: Vec<Example> = serde_json::from_str(
-            &std::fs::read_to_string(puzzle.examples_path).expect("unable to read examples"),
-        )
-        .expect("unable to parse examples");
-
-        for example in examples.iter().collect::<Vec<&Example>>() {
-            assert_eq!(
-                T::solve(T::parse(&example.sample)).expect(format!("unable to solve").as_str()),
-                example.expected
-            );
+        let puzzle = name.to_string();
+
+        match Runner::report(name) {
+            Ok(Verdict::Passed) => {}
+            Ok(Verdict::Failed) => panic!("puzzle[{}]: some examples failed", puzzle),
+            Ok(Verdict::Unverified) => panic!("puzzle[{}]: no example has a known answer", puzzle),
+            Err(err) => panic!("puzzle[{}]: {}", puzzle, err),
+        }
+    }
+
+    pub fn report<T: Solution + Display>(name: T) -> Result<Verdict, String> {
+        let puzzle = Puzzle::parse(&name.to_string())?;
+
+        let examples = std::fs::read_to_string(&puzzle.examples_path)
+            .map_err(|err| format!("unable to read {}: {}", puzzle.examples_path, err))?;
+
+        let examples: Vec<Example> = serde_json::from_str(&examples)
+            .map_err(|err| format!("unable to parse {}: {}", puzzle.examples_path, err))?;
+
+        let part = puzzle
+            .part
+            .parse::<u8>()
+            .map_err(|_| "part must be a number")?;
+
+        let mut passed = 0;
+        let mut failed = 0;
+        let mut unknown = 0;
+
+        for (i, example) in examples.iter().enumerate() {
+            if example.part.is_some_and(|p| p != part) {
+                continue;
+            }
+
+            let label = match &example.name {
+                Some(name) => name.clone(),
+                None => format!("#{}", i + 1),
+            };
+
+            let (answer, time) = Runner::timed(|| {
+                T::parse(&example.sample)
+                    .map_err(Error::from)
+                    .and_then(|parsed| T::solve_with(parsed, &example.params).map_err(Error::from))
+            });
+
+            let expected = match &example.expected {
+                Some(expected) if !example.pending => expected,
+                _ => {
+                    unknown += 1;
+                    match answer {
+                        Ok(answer) => println!(
+                            "puzzle[{}]: example[{}] status=unknown answer={} time={}",
+                            name, label, answer, time
+                        ),
+                        Err(err) => println!(
+                            "puzzle[{}]: example[{}] status=unknown time={} reason={}",
+                            name, label, time, err
+                        ),
+                    }
+                    continue;
+                }
+            };
+
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    failed += 1;
+                    println!(
+                        "puzzle[{}]: example[{}] status=error time={} reason={}",
+                        name, label, time, err
+                    );
+                    continue;
+                }
+            };
+
+            if answer.matches(expected) {
+                passed += 1;
+                println!(
+                    "puzzle[{}]: example[{}] status=ok answer={} time={}",
+                    name, label, answer, time
+                );
+                continue;
+            }
+
+            failed += 1;
+
+            let actual = match &answer {
+                Answer::Art(lines) => lines.join("\n"),
+                answer => answer.to_string(),
+            };
+
+            if actual.contains('\n') || expected.trim_matches('\n').contains('\n') {
+                println!(
+                    "puzzle[{}]: example[{}] status=failed time={}",
+                    name, label, time
+                );
+                Runner::diff(expected.trim_matches('\n'), &actual);
+            } else {
+                println!(
+                    "puzzle[{}]: example[{}] status=failed expected={} answer={} time={}",
+                    name, label, expected, answer, time
+                );
+            }
+        }
+
+        if passed + failed + unknown == 0 {
+            println!("puzzle[{}]: no examples", name);
+        }
+
+        println!(
+            "puzzle[{}]: examples passed={} failed={} unknown={}",
+            name, passed, failed, unknown
+        );
+
+        Ok(Verdict::new(passed, failed))
+    }
+
+    fn diff(expected: &str, actual: &str) {
+        let expected: Vec<_> = expected.lines().map(str::trim_end).collect();
+        let actual: Vec<_> = actual.lines().map(str::trim_end).collect();
+
+        for i in 0..expected.len().max(actual.len()) {
+            match (expected.get(i), actual.get(i)) {
+                (Some(e), Some(a)) if e == a => println!("  {}", e),
+                (e, a) => {
+                    if let Some(e) = e {
+                        println!("- {}", e);
+                    }
+                    if let Some(a) = a {
+                        println!("+ {}", a);
+                    }
+                }
+            }
+        }
+    }
+
+    fn answer<T: Solution>(puzzle: &Puzzle, source: &Source) -> Result<(Answer, String), Error> {
+        let input = source.read(puzzle).map_err(Error::Input)?;
+        let parsed = T::parse(input.trim_end())?;
+        let (output, time) = Runner::timed(|| T::solve(parsed));
+
+        Ok((output?, time))
+    }
+
+    pub fn run<T: Solution>(input: &str) -> Run {
+        let (parsed, parse_time) = Runner::timed(|| T::parse(input.trim_end()));
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                return Run {
+                    answer: Err(err.into()),
+                    parse_time,
+                    solve_time: "-".to_string(),
+                }
+            }
+        };
+
+        let (answer, solve_time) = Runner::timed(|| T::solve(parsed));
+
+        Run {
+            answer: answer.map_err(Error::from),
+            parse_time,
+            solve_time,
+        }
+    }
+
+    // both parts of a day share the parsed input, the parse time is reported with the first
+    pub fn run_day<T: Day>(input: &str, second: bool) -> Vec<Run> {
+        let (parsed, parse_time) = Runner::timed(|| T::parse(input.trim_end()));
+
+        let parts = match second {
+            true => 2,
+            false => 1,
+        };
+
+        let parse_times = std::iter::once(parse_time).chain(std::iter::repeat("-".to_string()));
+
+        (1..=parts)
+            .zip(parse_times)
+            .map(|(part, parse_time)| match &parsed {
+                Ok(parsed) => {
+                    let (answer, solve_time) = Runner::timed(|| match part {
+                        1 => T::part1(parsed),
+                        _ => T::part2(parsed),
+                    });
+
+                    Run {
+                        answer: answer.map_err(Error::from),
+                        parse_time,
+                        solve_time,
+                    }
+                }
+                Err(err) => Run {
+                    answer: Err(err.clone().into()),
+                    parse_time,
+                    solve_time: "-".to_string(),
+                },
+            })
+            .collect()
+    }
+
+    fn remaining(duration: std::time::Duration) -> String {
+        let secs = duration.as_secs();
+
+        match (secs / 3600, secs % 3600 / 60, secs % 60) {
+            (0, 0, s) => format!("{}s", s),
+            (0, m, s) => format!("{}m {}s", m, s),
+            (h, m, s) => format!("{}h {}m {}s", h, m, s),
         }
     }
 
@@ -221,17 +1054,36 @@ impl Runner {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct Example {
+pub struct Example {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<u8>,
     pub sample: String,
-    pub expected: String,
+    #[serde(default)]
+    pub expected: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pending: bool,
+    #[serde(default, skip_serializing_if = "Params::is_empty")]
+    pub params: Params,
 }
 
 #[macro_export]
 macro_rules! puzzle {
     ($name:literal) => {
         use std::fmt::Display;
+        #[allow(unused_imports)]
+        use $crate::answer::Answer;
+        #[allow(unused_imports)]
+        use $crate::error::{ParseError, SolveError};
+        #[allow(unused_imports)]
+        use $crate::template::Params;
         use $crate::template::Solution;
 
+        pub const ID: &str = $name;
+
+        pub const ENTRIES: &[$crate::registry::Entry] = &[$crate::entry!(ID, Puzzle)];
+
         pub struct Puzzle;
 
         impl Display for Puzzle {
@@ -241,3 +1093,170 @@ macro_rules! puzzle {
         }
     };
 }
+
+#[macro_export]
+macro_rules! day {
+    ($name:literal) => {
+        use std::fmt::Display;
+        #[allow(unused_imports)]
+        use $crate::answer::Answer;
+        #[allow(unused_imports)]
+        use $crate::error::{ParseError, SolveError};
+        #[allow(unused_imports)]
+        use $crate::template::Params;
+        use $crate::template::{Day, Solution};
+
+        // the last day of an event has no second part to register
+        pub const ENTRIES: &[$crate::registry::Entry] = match $crate::registry::second_part($name) {
+            true => &[
+                $crate::entry!(concat!($name, "_01"), Part1, run_day),
+                $crate::entry!(concat!($name, "_02"), Part2, run_day),
+            ],
+            false => &[$crate::entry!(concat!($name, "_01"), Part1, run_day)],
+        };
+
+        fn run_day(input: &str) -> Vec<$crate::template::Run> {
+            $crate::template::Runner::run_day::<Puzzle>(input, $crate::registry::second_part($name))
+        }
+
+        pub struct Puzzle;
+
+        pub struct Part1;
+
+        pub struct Part2;
+
+        impl Display for Part1 {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!($name, "_01"))
+            }
+        }
+
+        impl Display for Part2 {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!($name, "_02"))
+            }
+        }
+
+        impl Solution for Part1 {
+            type Input = <Puzzle as Day>::Input;
+
+            fn parse(input: &str) -> Result<Self::Input, ParseError> {
+                <Puzzle as Day>::parse(input)
+            }
+
+            fn solve(input: Self::Input) -> Result<Answer, SolveError> {
+                <Puzzle as Day>::part1(&input)
+            }
+
+            fn solve_with(input: Self::Input, params: &Params) -> Result<Answer, SolveError> {
+                <Puzzle as Day>::part1_with(&input, params)
+            }
+        }
+
+        impl Solution for Part2 {
+            type Input = <Puzzle as Day>::Input;
+
+            fn parse(input: &str) -> Result<Self::Input, ParseError> {
+                <Puzzle as Day>::parse(input)
+            }
+
+            fn solve(input: Self::Input) -> Result<Answer, SolveError> {
+                <Puzzle as Day>::part2(&input)
+            }
+
+            fn solve_with(input: Self::Input, params: &Params) -> Result<Answer, SolveError> {
+                <Puzzle as Day>::part2_with(&input, params)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Numbers;
+
+    impl Day for Numbers {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            PARSES.fetch_add(1, Ordering::SeqCst);
+
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .map_err(|_| ParseError::new(i + 1, 1, "not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(input.iter().product::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn day_runs_both_parts_on_a_single_parse() {
+        let runs = Runner::run_day::<Numbers>("2\n3\n4\n", true);
+
+        assert_eq!(PARSES.load(Ordering::SeqCst), 1);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].answer, Ok(Answer::Integer(9)));
+        assert_eq!(runs[1].answer, Ok(Answer::Integer(24)));
+        assert_eq!(runs[1].parse_time, "-");
+        assert_eq!(Runner::run_day::<Numbers>("2", false).len(), 1);
+
+        let runs = Runner::run_day::<Numbers>("x", true);
+
+        assert!(runs.iter().all(|run| run.answer.is_err()));
+    }
+
+    #[test]
+    fn examples_without_known_answers_are_unverified() {
+        assert_eq!(Verdict::new(0, 0), Verdict::Unverified);
+        assert_eq!(Verdict::new(2, 0), Verdict::Passed);
+        assert_eq!(Verdict::new(2, 1), Verdict::Failed);
+        assert_eq!(Verdict::new(0, 1), Verdict::Failed);
+    }
+
+    fn at(year: i32, month: u32, day: u32) -> chrono::NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .expect("invalid date")
+    }
+
+    #[test]
+    fn today_is_a_released_day_of_december() {
+        let id = |now| Target::id_at("today", None, now);
+
+        assert_eq!(id(at(2023, 12, 7)), Ok((2023, 7, None)));
+        assert_eq!(id(at(2024, 12, 25)), Ok((2024, 25, None)));
+        assert!(id(at(2025, 12, 13)).is_err());
+        assert!(id(at(2024, 11, 30)).is_err());
+    }
+
+    #[test]
+    fn short_ids_prefer_the_default_event_when_they_end_with_a_part() {
+        let now = at(2024, 6, 1);
+        let id = |input, default| Target::id_at(input, default, now);
+
+        assert_eq!(id("15.1", Some(2022)), Ok((2022, 15, Some(1))));
+        assert_eq!(id("07_02", Some(2022)), Ok((2022, 7, Some(2))));
+        assert_eq!(id("15.7", Some(2022)), Ok((2015, 7, None)));
+        assert_eq!(id("7", Some(2022)), Ok((2022, 7, None)));
+        assert_eq!(id("15.1", None), Ok((2015, 1, None)));
+        assert_eq!(id("2015.1", Some(2022)), Ok((2015, 1, None)));
+        assert!(id("7", None).is_err());
+    }
+}
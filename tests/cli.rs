@@ -0,0 +1,623 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use tempfile::TempDir;
+
+const DESCRIPTION: &str = "## \\--- Day 1: Not Quite Lisp ---
+
+For example:
+
+```
+(())
+```
+
+results in floor `0`.
+";
+
+const EMPTY_PUZZLES: &str = "crate::puzzles! {\n}\n";
+
+// a project without puzzles, next to the fixtures its provider serves day 1 of 2015 from
+struct Workspace {
+    root: TempDir,
+    fixtures: TempDir,
+}
+
+impl Workspace {
+    fn new(answer: &str) -> Self {
+        let workspace = Self {
+            root: tempfile::tempdir().expect("failed to create workspace"),
+            fixtures: tempfile::tempdir().expect("failed to create fixtures"),
+        };
+
+        for dir in ["data/inputs", "data/examples", "data/answers"] {
+            std::fs::create_dir_all(workspace.path(dir)).expect("failed to create dir");
+        }
+
+        workspace.write("src/puzzles/mod.rs", EMPTY_PUZZLES);
+        workspace.fixture("inputs/2015_01.txt", "((())");
+        workspace.fixture("descriptions/2015_01.md", DESCRIPTION);
+        workspace.fixture(
+            "answers.json",
+            &format!("{{ \"2015_01_01\": \"{}\" }}", answer),
+        );
+
+        workspace
+    }
+
+    fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.path().join(path)
+    }
+
+    fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.path(path).exists()
+    }
+
+    fn read(&self, path: impl AsRef<Path>) -> String {
+        std::fs::read_to_string(self.path(path)).expect("failed to read file")
+    }
+
+    fn write(&self, path: impl AsRef<Path>, content: &str) {
+        write(&self.path(path), content);
+    }
+
+    fn fixture(&self, path: impl AsRef<Path>, content: &str) {
+        write(&self.fixtures.path().join(path), content);
+    }
+
+    fn aoc(&self, args: &[&str]) -> Output {
+        self.command(args)
+            .stdin(Stdio::null())
+            .output()
+            .expect("failed to run aoc")
+    }
+
+    fn aoc_with_stdin(&self, args: &[&str], stdin: &str) -> Output {
+        let mut child = self
+            .command(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to run aoc");
+
+        child
+            .stdin
+            .take()
+            .expect("missing stdin")
+            .write_all(stdin.as_bytes())
+            .expect("failed to write stdin");
+
+        child.wait_with_output().expect("failed to run aoc")
+    }
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+
+        command
+            .args(args)
+            .current_dir(self.root.path())
+            .env("AOC_FIXTURES", self.fixtures.path());
+
+        command
+    }
+}
+
+fn write(path: &Path, content: &str) {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).expect("failed to create dir");
+    }
+
+    std::fs::write(path, content).expect("failed to write file");
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+#[test]
+fn scaffold_downloads_from_provider() {
+    let workspace = Workspace::new("1");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace
+        .read("src/puzzles/mod.rs")
+        .contains("        d01 { part1 },\n"));
+    assert!(workspace.exists("src/puzzles/y2015/d01/part1/solution.rs"));
+    assert_eq!(workspace.read("data/inputs/2015_01.txt"), "((())");
+    assert_eq!(
+        workspace.read("src/puzzles/y2015/d01/part1/description.md"),
+        DESCRIPTION
+    );
+
+    let examples: serde_json::Value =
+        serde_json::from_str(&workspace.read("data/examples/2015_01_01.json"))
+            .expect("failed to parse examples");
+
+    assert_eq!(examples[0]["sample"], "(())");
+    assert_eq!(examples[0]["expected"], "0");
+}
+
+#[test]
+fn scaffold_offline_skips_provider() {
+    let workspace = Workspace::new("1");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01", "--offline"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace.exists("src/puzzles/y2015/d01/part1/solution.rs"));
+    assert!(!workspace.exists("data/inputs/2015_01.txt"));
+    assert!(!workspace.exists("src/puzzles/y2015/d01/part1/description.md"));
+}
+
+#[test]
+fn submit_records_accepted_answer() {
+    let workspace = Workspace::new("1");
+
+    workspace.write("data/inputs/2015_01.txt", "((())");
+
+    let output = workspace.aoc(&["submit", "2015_01_01"]);
+
+    assert!(stdout(&output).contains("status=correct"), "{:?}", output);
+    assert!(workspace
+        .read("data/answers/2015_01.json")
+        .contains("\"accepted\": 1"));
+
+    let output = workspace.aoc(&["submit", "2015_01_01"]);
+
+    assert!(stdout(&output).contains("status=blocked"), "{:?}", output);
+
+    // the fixtures have no answer for the second part, the submission fails
+    workspace.write("data/inputs/2015_01.txt", ")((");
+
+    let output = workspace.aoc(&["submit", "2015_01_02"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("unable to submit"));
+}
+
+#[test]
+fn submit_reports_hint_for_wrong_answer() {
+    let workspace = Workspace::new("5");
+
+    workspace.write("data/inputs/2015_01.txt", "((())");
+
+    let output = workspace.aoc(&["submit", "2015_01_01"]);
+
+    assert!(
+        stdout(&output).contains("status=incorrect hint=too-low"),
+        "{:?}",
+        output
+    );
+    assert!(workspace
+        .read("data/answers/2015_01.json")
+        .contains("too-low"));
+    assert!(workspace
+        .read("data/metadata/2015_01.json")
+        .contains("\"01\": \"attempted\""));
+}
+
+#[test]
+fn scaffold_follows_config() {
+    let workspace = Workspace::new("1");
+
+    workspace.write(
+        "aoc.toml",
+        "year = 2015\n\n[paths]\ninputs = \"inputs\"\nexamples = \"examples\"\n",
+    );
+
+    let output = workspace.aoc(&["scaffold", "01_01"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace
+        .read("src/puzzles/mod.rs")
+        .contains("        d01 { part1 },\n"));
+    assert!(workspace.exists("src/puzzles/y2015/d01/part1/solution.rs"));
+    assert!(workspace.exists("examples/2015_01_01.json"));
+    assert_eq!(workspace.read("inputs/2015_01.txt"), "((())");
+}
+
+#[test]
+fn invalid_config_is_reported() {
+    let workspace = Workspace::new("1");
+
+    let fails = |config: &str, error: &str| {
+        workspace.write("aoc.toml", config);
+
+        let output = workspace.aoc(&["run-all"]);
+
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains(error), "{:?}", output);
+    };
+
+    fails("[paths]\nunknown = \"x\"\n", "unable to parse aoc.toml");
+
+    // puzzle modules are compiled into the crate, their path is fixed
+    fails(
+        "[paths]\npuzzles = \"puzzles\"\n",
+        "unknown field `puzzles`",
+    );
+
+    // profile names become dirs, they can't point outside of the data dirs
+    fails(
+        "[profiles.\"../alice\"]\n",
+        "invalid profile name: \"../alice\"",
+    );
+
+    // the fixtures are a test hook, not a setting
+    fails("[provider]\nfixtures = \"x\"\n", "unknown field `provider`");
+}
+
+#[test]
+fn compare_runs_every_profile() {
+    let workspace = Workspace::new("1");
+
+    workspace.fixture("inputs/alice/2015_01.txt", "((())");
+    workspace.fixture("inputs/bob/2015_01.txt", "(((");
+    workspace.write(
+        "aoc.toml",
+        "[profiles.alice]\nfile = \"alice.session\"\n\n[profiles.bob]\nfile = \"bob.session\"\n",
+    );
+
+    let output = workspace.aoc(&["compare", "2015_01_01", "--fetch"]);
+    let stdout = stdout(&output);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.starts_with("alice") && line.contains(" 1 ")),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.starts_with("bob") && line.contains(" 3 ")),
+        "{}",
+        stdout
+    );
+    assert_eq!(workspace.read("data/inputs/bob/2015_01.txt"), "(((");
+}
+
+#[test]
+fn submit_uses_active_profile() {
+    let workspace = Workspace::new("1");
+
+    workspace.fixture("answers/bob.json", "{ \"2015_01_01\": \"3\" }");
+    workspace.write("data/inputs/bob/2015_01.txt", "(((");
+    workspace.write(
+        "aoc.toml",
+        "profile = \"bob\"\n\n[profiles.bob]\nfile = \"bob.session\"\n",
+    );
+
+    let output = workspace.aoc(&["submit", "2015_01_01"]);
+
+    assert!(stdout(&output).contains("status=correct"), "{:?}", output);
+    assert!(workspace.exists("data/answers/bob/2015_01.json"));
+    assert!(!workspace.exists("data/answers/2015_01.json"));
+
+    // the star is bob's, the default account has not earned it
+    assert!(workspace
+        .read("data/metadata/bob/2015_01.json")
+        .contains("\"01\": \"starred\""));
+    assert!(!workspace.exists("data/metadata/2015_01.json"));
+
+    let output = workspace.aoc(&["list"]);
+
+    assert!(stdout(&output).contains("puzzle[2015_01]: title=\"\" stars=1"));
+}
+
+#[test]
+fn solve_reads_alternative_inputs() {
+    let workspace = Workspace::new("1");
+
+    workspace.write("stress.txt", "((((");
+
+    let output = workspace.aoc(&["solve", "2015_01_01", "--input", "stress.txt"]);
+
+    assert!(stdout(&output).contains("answer=4"));
+
+    let output = workspace.aoc(&["solve", "2015_01_01", "--inline", "(()"]);
+
+    assert!(stdout(&output).contains("answer=1"));
+
+    let output = workspace.aoc_with_stdin(&["solve", "2015_01_01", "--stdin"], "))");
+
+    assert!(stdout(&output).contains("answer=-2"));
+    assert!(!workspace.exists("data/inputs/2015_01.txt"));
+
+    let output = workspace.aoc(&["solve", "2015_01_01", "--input", "missing.txt"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("unable to read missing.txt"));
+}
+
+#[test]
+fn scaffold_dry_run_writes_nothing() {
+    let workspace = Workspace::new("1");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01", "--dry-run"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    // the plan printed is the plan applied, downloads included
+    for path in [
+        "src/puzzles/y2015/d01/part1/solution.rs",
+        "src/puzzles/y2015/d01/part1/description.md",
+        "data/inputs/2015_01.txt",
+    ] {
+        assert!(stdout(&output).contains(path), "{:?}", output);
+    }
+    assert!(!workspace.exists("src/puzzles/y2015"));
+    assert!(!workspace.exists("data/inputs/2015_01.txt"));
+    assert_eq!(workspace.read("src/puzzles/mod.rs"), EMPTY_PUZZLES);
+}
+
+#[test]
+fn scaffold_is_idempotent() {
+    let workspace = Workspace::new("1");
+
+    assert!(workspace.aoc(&["scaffold", "2015_01_01"]).status.success());
+    assert!(workspace.aoc(&["scaffold", "2015_01_01"]).status.success());
+    assert_eq!(
+        workspace
+            .read("src/puzzles/mod.rs")
+            .matches("d01 { part1 }")
+            .count(),
+        1
+    );
+
+    workspace.write("src/puzzles/y2015/d01/part1/solution.rs", "// solved");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        workspace.read("src/puzzles/y2015/d01/part1/solution.rs"),
+        "// solved"
+    );
+}
+
+#[test]
+fn scaffold_rolls_back_on_failure() {
+    let workspace = Workspace::new("1");
+
+    // a file where the bin dir should be makes the last write fail
+    workspace.write("src/bin", "");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01", "--bin"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("rolled back"));
+    assert!(!workspace.exists("src/puzzles/y2015"));
+    assert!(!workspace.exists("data/inputs/2015_01.txt"));
+    assert!(!workspace.exists("data/examples/2015_01_01.json"));
+    assert_eq!(workspace.read("src/puzzles/mod.rs"), EMPTY_PUZZLES);
+}
+
+#[test]
+fn migrate_groups_puzzles_by_year_and_day() {
+    let workspace = Workspace::new("1");
+
+    for module in ["_2015_06", "_2015_01_02", "_2015_01_01"] {
+        workspace.write(format!("src/puzzles/{}/mod.rs", module), "");
+    }
+
+    workspace.write(
+        "src/puzzles/mod.rs",
+        "crate::puzzles! {\n    _2015_06,\n    _2015_01_02,\n\n    _2015_01_01,\n}\n",
+    );
+    workspace.write(
+        "src/bin/2015_06_01.rs",
+        "use aoc::puzzles::_2015_06::solution::Part1 as Puzzle;",
+    );
+
+    let output = workspace.aoc(&["migrate"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        workspace.read("src/puzzles/mod.rs"),
+        "crate::puzzles! {\n    y2015 {\n        d01 { part1, part2 },\n        d06,\n    },\n}\n"
+    );
+    assert!(workspace.exists("src/puzzles/y2015/d01/part1/mod.rs"));
+    assert!(workspace.exists("src/puzzles/y2015/d06/mod.rs"));
+    assert!(!workspace.exists("src/puzzles/_2015_06"));
+    assert_eq!(
+        workspace.read("src/bin/2015_06_01.rs"),
+        "use aoc::puzzles::y2015::d06::solution::Part1 as Puzzle;"
+    );
+
+    let output = workspace.aoc(&["scaffold", "2015_06_01"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("day-level"));
+}
+
+#[test]
+fn scaffold_whole_day_skips_started_parts() {
+    let workspace = Workspace::new("1");
+
+    assert!(workspace.aoc(&["scaffold", "2015_01_01"]).status.success());
+
+    workspace.write("src/puzzles/y2015/d01/part1/solution.rs", "// solved");
+
+    let output = workspace.aoc(&["scaffold", "2015_01"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stdout(&output).contains("already scaffolded"));
+    assert_eq!(
+        workspace.read("src/puzzles/y2015/d01/part1/solution.rs"),
+        "// solved"
+    );
+    assert!(workspace.exists("src/puzzles/y2015/d01/part2/solution.rs"));
+    assert!(workspace
+        .read("src/puzzles/mod.rs")
+        .contains("d01 { part1, part2 },"));
+}
+
+#[test]
+fn scaffold_whole_event() {
+    let workspace = Workspace::new("1");
+
+    // only day 1 has fixtures, the days failing to download are skipped
+    let output = workspace.aoc(&["scaffold", "2015", "--day-level"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stderr(&output).contains("puzzle[2015_02_01]: failed to download"));
+    assert_eq!(workspace.read("data/inputs/2015_01.txt"), "((())");
+    assert!(!workspace.exists("src/puzzles/y2015/d02"));
+    assert!(!workspace.read("src/puzzles/mod.rs").contains("d02"));
+
+    let output = workspace.aoc(&["scaffold", "2015", "--day-level", "--offline"]);
+
+    assert!(output.status.success(), "{:?}", output);
+
+    let puzzles_mod = workspace.read("src/puzzles/mod.rs");
+
+    assert!(puzzles_mod.contains("        d01,\n"));
+    assert!(puzzles_mod.contains("        d25,\n"));
+    assert!(workspace.exists("src/puzzles/y2015/d25/solution.rs"));
+    assert!(workspace.exists("data/examples/2015_25_01.json"));
+    assert!(!workspace.exists("data/examples/2015_25_02.json"));
+}
+
+#[test]
+fn scaffold_renders_template_variants() {
+    let workspace = Workspace::new("1");
+
+    workspace.fixture("inputs/2015_01.txt", "#.#\n..#\n");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_01", "--template", "grid"]);
+    let solution = workspace.read("src/puzzles/y2015/d01/part1/solution.rs");
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(solution.starts_with("// --- Day 1: Not Quite Lisp ---\n// input: grid, 2 line(s)"));
+    assert!(solution.contains("crate::puzzle!(\"2015_01_01\");"));
+    assert!(solution.contains("type Input = Vec<Vec<char>>;"));
+
+    // a project variant falls back to the builtin templates it does not define
+    workspace.write(
+        "templates/mine/solution.txt",
+        "// {{ title }}, day {{ day }} part {{ part }} of {{ year }}\n",
+    );
+    workspace.write("aoc.toml", "[templates]\ndir = \"templates\"\n");
+
+    let output = workspace.aoc(&["scaffold", "2015_01_02", "--template", "mine"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        workspace.read("src/puzzles/y2015/d01/part2/solution.rs"),
+        "// Not Quite Lisp, day 1 part 2 of 2015\n"
+    );
+    assert_eq!(
+        workspace.read("src/puzzles/y2015/d01/part2/mod.rs"),
+        "pub mod solution;\n"
+    );
+
+    let output = workspace.aoc(&["scaffold", "2015_02_01", "--template", "missing"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("unknown template: missing"));
+}
+
+#[test]
+fn metadata_follows_scaffold_and_submit() {
+    let workspace = Workspace::new("1");
+
+    assert!(workspace.aoc(&["scaffold", "2015_01_01"]).status.success());
+
+    let metadata: serde_json::Value =
+        serde_json::from_str(&workspace.read("data/metadata/2015_01.json"))
+            .expect("failed to parse metadata");
+
+    assert_eq!(metadata["title"], "Not Quite Lisp");
+    assert_eq!(metadata["url"], "https://adventofcode.com/2015/day/1");
+    assert_eq!(metadata["released"], "2015-12-01T00:00:00-05:00");
+    assert_eq!(metadata["parts"]["01"], "unsolved");
+
+    // a run alone does not tell whether the answer is right
+    assert!(workspace.aoc(&["solve", "2015_01_01"]).status.success());
+    assert!(workspace
+        .read("data/metadata/2015_01.json")
+        .contains("\"01\": \"unsolved\""));
+
+    assert!(workspace.aoc(&["submit", "2015_01_01"]).status.success());
+
+    let output = workspace.aoc(&[
+        "meta",
+        "2015_01",
+        "--tag",
+        "parsing",
+        "--difficulty",
+        "easy",
+    ]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(
+        stdout(&output).contains(
+            "puzzle[2015_01]: title=\"Not Quite Lisp\" stars=1 tags=parsing difficulty=\"easy\""
+        ),
+        "{:?}",
+        output
+    );
+    assert!(
+        stdout(&output).contains("puzzle[2015_01_01]: starred"),
+        "{:?}",
+        output
+    );
+
+    let list = |args: &[&str]| stdout(&workspace.aoc(&[&["list"], args].concat()));
+
+    assert!(list(&["--tag", "parsing"]).contains("puzzle[2015_01]"));
+    assert!(list(&["lisp"]).contains("puzzle[2015_01]"));
+    assert!(list(&["--stars", "2"]).contains("no puzzles found"));
+    assert!(list(&["--tag", "graph"]).contains("no puzzles found"));
+
+    // a broken file is reported and the other days are still listed
+    workspace.write("data/metadata/2015_02.json", "{");
+
+    let output = workspace.aoc(&["list"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stdout(&output).contains("puzzle[2015_01]"));
+    assert!(stderr(&output).contains("puzzle[2015_02]: unable to parse"));
+}
+
+#[test]
+fn scaffold_event_before_it_starts() {
+    let workspace = Workspace::new("1");
+
+    // the event of the current year is open for scaffolding, even before december
+    let now = chrono::Utc::now().naive_utc() - chrono::Duration::hours(5);
+    let year = chrono::Datelike::year(&now).to_string();
+
+    let output = workspace.aoc(&["scaffold", &year, "--offline", "--day-level"]);
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(workspace
+        .read("src/puzzles/mod.rs")
+        .contains(&format!("    y{} {{\n        d01,\n", year)));
+
+    let output = workspace.aoc(&["run-all"]);
+
+    assert!(output.status.success(), "{:?}", output);
+
+    let output = workspace.aoc(&["verify"]);
+
+    assert!(!stderr(&output).contains("panicked"), "{:?}", output);
+
+    // the ids of the event are valid, the modules are only compiled in with the project
+    let output = workspace.aoc(&["solve", &format!("{}_01_01", year)]);
+
+    assert!(
+        stderr(&output).contains("puzzle is not registered"),
+        "{:?}",
+        output
+    );
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// puzzle modules are declared by the `puzzles!` macro, so `cargo fmt` never reaches them
+fn sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir).expect("failed to read dir");
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            sources(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn puzzles_are_formatted() {
+    let mut files = vec![];
+
+    sources(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src/puzzles"),
+        &mut files,
+    );
+
+    let output = match Command::new("rustfmt")
+        .args(["--check", "--edition", "2021"])
+        .args(&files)
+        .output()
+    {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("rustfmt is not available, skipped: {}", err);
+            return;
+        }
+    };
+
+    assert!(
+        output.status.success(),
+        "puzzles are not formatted, run `rustfmt --edition 2021` on them:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
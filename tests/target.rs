@@ -0,0 +1,86 @@
+use aoc::template::{Puzzle, Target};
+
+fn ids(input: &str) -> Vec<String> {
+    Target::parse(input)
+        .expect("failed to parse target")
+        .puzzles
+        .into_iter()
+        .map(|puzzle| puzzle.id)
+        .collect()
+}
+
+#[test]
+fn parses_alternative_separators() {
+    assert_eq!(ids("2015_07_02"), ["2015_07_02"]);
+    assert_eq!(ids("2015/7/2"), ["2015_07_02"]);
+    assert_eq!(ids("2015:7:2"), ["2015_07_02"]);
+    assert_eq!(ids("15.7.2"), ["2015_07_02"]);
+}
+
+#[test]
+fn parses_whole_days() {
+    assert_eq!(ids("2015-07"), ["2015_07_01", "2015_07_02"]);
+    assert_eq!(ids("15.7"), ["2015_07_01", "2015_07_02"]);
+    assert!(Puzzle::parse("2015-07").is_err());
+}
+
+#[test]
+fn rejects_out_of_range_ids() {
+    assert!(Target::parse("9999_01_01").is_err());
+    assert!(Target::parse("2014_01_01").is_err());
+    assert!(Target::parse("2015_00_01").is_err());
+    assert!(Target::parse("2015_26_01").is_err());
+    assert!(Target::parse("2015_01_03").is_err());
+    assert!(Target::parse("2015_01_x").is_err());
+    assert!(Target::parse("2015__01").is_err());
+}
+
+#[test]
+fn parses_whole_events() {
+    let puzzles = Target::event("2015")
+        .expect("failed to parse event")
+        .puzzles;
+
+    assert_eq!(puzzles.len(), 49);
+    assert_eq!(puzzles[0].id, "2015_01_01");
+    assert_eq!(puzzles[48].id, "2015_25_01");
+    assert_eq!(
+        Target::event("2015_07")
+            .expect("failed to parse day")
+            .puzzles
+            .len(),
+        2
+    );
+    assert!(Target::parse("2015").is_err());
+    assert!(Target::event("2014").is_err());
+}
+
+#[test]
+fn events_from_2025_run_for_12_days() {
+    assert_eq!(Target::days(2024), 25);
+    assert_eq!(Target::days(2025), 12);
+    assert!(Target::parse("2025_12_01").is_ok());
+    assert!(Target::parse("2025_13_01").is_err());
+    assert_eq!(
+        Target::event("2025")
+            .expect("failed to parse event")
+            .puzzles
+            .last()
+            .map(|puzzle| puzzle.id.clone()),
+        Some("2025_12_01".to_string())
+    );
+}
+
+#[test]
+fn last_day_has_a_single_part() {
+    assert!(Target::parse("2015_25_01").is_ok());
+    assert!(Target::parse("2015_25_02").is_err());
+    assert!(Target::parse("2025_12_02").is_err());
+    assert_eq!(
+        Target::parse("2015_25")
+            .expect("failed to parse day")
+            .puzzles
+            .len(),
+        1
+    );
+}